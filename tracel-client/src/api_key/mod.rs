@@ -0,0 +1,46 @@
+pub mod request;
+pub mod response;
+
+use crate::{
+    Client, ClientError,
+    api_key::{
+        request::CreateApiKeyRequest,
+        response::{ApiKeyListResponse, ApiKeySecretResponse},
+    },
+};
+
+impl Client {
+    /// List the API keys of the logged-in user. Secrets are not included.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_api_keys(&self) -> Result<ApiKeyListResponse, ClientError> {
+        self.transport.get_json("user/api-keys")
+    }
+
+    /// Create an API key for the logged-in user. The secret of the key is only
+    /// part of this response.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn create_api_key(
+        &self,
+        request: CreateApiKeyRequest,
+    ) -> Result<ApiKeySecretResponse, ClientError> {
+        self.transport.post_json("user/api-keys", Some(request))
+    }
+
+    /// Replace the secret of an API key, keeping its name, scopes and expiry. The
+    /// previous secret stops working immediately.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn rotate_api_key(&self, api_key_id: &str) -> Result<ApiKeySecretResponse, ClientError> {
+        self.transport
+            .post_json(format!("user/api-keys/{api_key_id}/rotate"), None::<()>)
+    }
+
+    /// Revoke an API key. Clients logged in with it lose access.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn revoke_api_key(&self, api_key_id: &str) -> Result<(), ClientError> {
+        self.transport.delete(format!("user/api-keys/{api_key_id}"))
+    }
+}
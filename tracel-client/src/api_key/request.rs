@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+/// What an API key is allowed to do.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, Hash, strum::Display)]
+pub enum ApiKeyScopeRequest {
+    #[serde(rename = "projects:read")]
+    #[strum(serialize = "projects:read")]
+    ProjectsRead,
+    #[serde(rename = "projects:write")]
+    #[strum(serialize = "projects:write")]
+    ProjectsWrite,
+    #[serde(rename = "experiments:write")]
+    #[strum(serialize = "experiments:write")]
+    ExperimentsWrite,
+    #[serde(rename = "models:read")]
+    #[strum(serialize = "models:read")]
+    ModelsRead,
+    #[serde(rename = "models:write")]
+    #[strum(serialize = "models:write")]
+    ModelsWrite,
+    #[serde(rename = "jobs:write")]
+    #[strum(serialize = "jobs:write")]
+    JobsWrite,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+    pub scopes: Vec<ApiKeyScopeRequest>,
+    /// Lifetime of the key. The key never expires when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in_seconds: Option<u64>,
+}
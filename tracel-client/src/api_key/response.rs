@@ -0,0 +1,71 @@
+use std::fmt::{Debug, Formatter};
+
+use serde::Deserialize;
+
+use crate::credentials::TracelCredentials;
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, strum::Display)]
+pub enum ApiKeyScopeResponse {
+    #[serde(rename = "projects:read")]
+    #[strum(serialize = "projects:read")]
+    ProjectsRead,
+    #[serde(rename = "projects:write")]
+    #[strum(serialize = "projects:write")]
+    ProjectsWrite,
+    #[serde(rename = "experiments:write")]
+    #[strum(serialize = "experiments:write")]
+    ExperimentsWrite,
+    #[serde(rename = "models:read")]
+    #[strum(serialize = "models:read")]
+    ModelsRead,
+    #[serde(rename = "models:write")]
+    #[strum(serialize = "models:write")]
+    ModelsWrite,
+    #[serde(rename = "jobs:write")]
+    #[strum(serialize = "jobs:write")]
+    JobsWrite,
+    #[serde(other)]
+    #[strum(serialize = "unknown")]
+    Unknown,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ApiKeyResponse {
+    pub id: String,
+    pub name: String,
+    /// First characters of the key, to tell keys apart without revealing them.
+    pub prefix: String,
+    pub scopes: Vec<ApiKeyScopeResponse>,
+    pub created_at: String,
+    pub expires_at: Option<String>,
+    pub last_used_at: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ApiKeyListResponse {
+    pub items: Vec<ApiKeyResponse>,
+}
+
+/// A newly created or rotated API key. The server only returns `secret` once; it
+/// cannot be retrieved later. Its `Debug` output leaves the secret out.
+#[derive(Deserialize, Clone)]
+pub struct ApiKeySecretResponse {
+    #[serde(flatten)]
+    pub key: ApiKeyResponse,
+    pub secret: String,
+}
+
+impl Debug for ApiKeySecretResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiKeySecretResponse")
+            .field("key", &self.key)
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+impl ApiKeySecretResponse {
+    pub fn credentials(&self) -> TracelCredentials {
+        TracelCredentials::new(self.secret.clone())
+    }
+}
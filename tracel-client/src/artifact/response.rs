@@ -1,5 +1,7 @@
 use serde::Deserialize;
 
+use crate::model::response::FileManifestResponse;
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct MultipartUploadResponse {
     pub id: String,
@@ -49,9 +51,11 @@ pub struct ArtifactResponse {
     pub kind: String,
     pub bucket_id: String,
     pub experiment: ArtifactSourceResponse,
-    pub manifest: serde_json::Value,
+    pub manifest: ArtifactManifestResponse,
 }
 
+pub type ArtifactManifestResponse = FileManifestResponse;
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct ArtifactListResponse {
     pub items: Vec<ArtifactResponse>,
@@ -1,4 +1,5 @@
 use reqwest::Url;
+use reqwest::header::HeaderValue;
 use serde::{Deserialize, Serialize};
 
 use crate::credentials::TracelCredentials;
@@ -61,6 +62,26 @@ impl Client {
         Ok(client)
     }
 
+    /// Create a new HttpClient that sends `token`, an API key or an access token, as
+    /// a bearer token on every request, including the experiment WebSocket.
+    ///
+    /// Unlike [`Client::new`], no login request is made and no session is kept, so an
+    /// invalid token is only reported by the first call that needs it. A token that
+    /// cannot be sent in a header, e.g. one with a trailing newline, is rejected here.
+    pub fn with_bearer_token(env: Env, token: impl Into<String>) -> Result<Self, ClientError> {
+        let token = token.into();
+        HeaderValue::from_str(&format!("Bearer {token}")).map_err(|_| {
+            ClientError::InvalidRequest(
+                "Bearer token contains characters not allowed in an HTTP header".to_string(),
+            )
+        })?;
+
+        Ok(Client {
+            transport: ApiTransport::new(env.get_url()).with_auth(Auth::Bearer(token)),
+            env,
+        })
+    }
+
     #[deprecated]
     /// Please use environment based constructor
     pub fn from_url(url: Url, credentials: &TracelCredentials) -> Result<Self, ClientError> {
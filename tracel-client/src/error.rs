@@ -51,6 +51,12 @@ pub enum ClientError {
         status: StatusCode,
         body: ApiErrorBody,
     },
+    #[error("Timed out: {0}")]
+    Timeout(String),
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("Checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
     #[error(transparent)]
     Serialization(#[from] serde_json::Error),
     #[error("Unknown Error: {0}")]
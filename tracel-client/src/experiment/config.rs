@@ -0,0 +1,203 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::{
+    WebSocketClient,
+    websocket::{ExperimentMessage, WebSocketError},
+};
+
+/// Structural schema of a logged configuration, inferred from its JSON form. Sent
+/// alongside the configuration so the UI can render typed fields.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConfigSchema {
+    Null,
+    Bool,
+    Integer,
+    Float,
+    String,
+    Array {
+        /// `None` for an empty array.
+        items: Option<Box<ConfigSchema>>,
+    },
+    Object {
+        fields: BTreeMap<String, ConfigSchema>,
+    },
+    /// Values of different types were found at the same place, e.g. in a
+    /// heterogeneous array.
+    Any,
+}
+
+impl ConfigSchema {
+    pub fn infer(value: &Value) -> Self {
+        match value {
+            Value::Null => ConfigSchema::Null,
+            Value::Bool(_) => ConfigSchema::Bool,
+            Value::Number(number) if number.is_f64() => ConfigSchema::Float,
+            Value::Number(_) => ConfigSchema::Integer,
+            Value::String(_) => ConfigSchema::String,
+            Value::Array(items) => ConfigSchema::Array {
+                items: items
+                    .iter()
+                    .map(ConfigSchema::infer)
+                    .reduce(ConfigSchema::merge)
+                    .map(Box::new),
+            },
+            Value::Object(map) => ConfigSchema::Object {
+                fields: map
+                    .iter()
+                    .map(|(key, value)| (key.clone(), ConfigSchema::infer(value)))
+                    .collect(),
+            },
+        }
+    }
+
+    fn merge(self, other: ConfigSchema) -> ConfigSchema {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (ConfigSchema::Integer, ConfigSchema::Float)
+            | (ConfigSchema::Float, ConfigSchema::Integer) => ConfigSchema::Float,
+            _ => ConfigSchema::Any,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            ConfigSchema::Null => "null",
+            ConfigSchema::Bool => "bool",
+            ConfigSchema::Integer => "integer",
+            ConfigSchema::Float => "float",
+            ConfigSchema::String => "string",
+            ConfigSchema::Array { .. } => "array",
+            ConfigSchema::Object { .. } => "object",
+            ConfigSchema::Any => "any",
+        }
+    }
+
+    /// Lists the fields that were added, removed or changed type between `self`
+    /// (the reference run) and `other`. Paths are dot-separated, with `[]` standing
+    /// for array items.
+    pub fn drift(&self, other: &ConfigSchema) -> Vec<ConfigDrift> {
+        let mut drifts = Vec::new();
+        Self::drift_at(String::new(), self, other, &mut drifts);
+        drifts
+    }
+
+    fn drift_at(path: String, from: &Self, to: &Self, drifts: &mut Vec<ConfigDrift>) {
+        match (from, to) {
+            (ConfigSchema::Object { fields: from }, ConfigSchema::Object { fields: to }) => {
+                let keys = from.keys().chain(to.keys()).collect::<BTreeSet<_>>();
+                for key in keys {
+                    let field_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    match (from.get(key), to.get(key)) {
+                        (Some(from), Some(to)) => Self::drift_at(field_path, from, to, drifts),
+                        (Some(_), None) => drifts.push(ConfigDrift {
+                            path: field_path,
+                            kind: ConfigDriftKind::Removed,
+                        }),
+                        (None, Some(_)) => drifts.push(ConfigDrift {
+                            path: field_path,
+                            kind: ConfigDriftKind::Added,
+                        }),
+                        (None, None) => {}
+                    }
+                }
+            }
+            (
+                ConfigSchema::Array { items: Some(from) },
+                ConfigSchema::Array { items: Some(to) },
+            ) => Self::drift_at(format!("{path}[]"), from, to, drifts),
+            // An empty array says nothing about its item type.
+            (ConfigSchema::Array { .. }, ConfigSchema::Array { .. }) => {}
+            (from, to) if from.kind() != to.kind() => drifts.push(ConfigDrift {
+                path,
+                kind: ConfigDriftKind::TypeChanged {
+                    from: from.kind(),
+                    to: to.kind(),
+                },
+            }),
+            _ => {}
+        }
+    }
+}
+
+/// A schema difference found by [`ConfigSchema::drift`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDrift {
+    pub path: String,
+    pub kind: ConfigDriftKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigDriftKind {
+    Added,
+    Removed,
+    TypeChanged {
+        from: &'static str,
+        to: &'static str,
+    },
+}
+
+impl WebSocketClient {
+    /// Logs a named configuration of the run along with its inferred schema.
+    pub fn log_config<T: Serialize>(
+        &mut self,
+        name: impl Into<String>,
+        config: &T,
+    ) -> Result<(), WebSocketError> {
+        let name = name.into();
+        let value = serde_json::to_value(config)
+            .map_err(|e| WebSocketError::SerializationError(e.to_string()))?;
+        let schema = ConfigSchema::infer(&value);
+
+        self.send(ExperimentMessage::Config {
+            value,
+            name: name.clone(),
+        })?;
+        self.send(ExperimentMessage::ConfigSchema { name, schema })
+    }
+
+    /// Logs the arguments the run was started with.
+    pub fn log_arguments<T: Serialize>(&mut self, arguments: &T) -> Result<(), WebSocketError> {
+        let value = serde_json::to_value(arguments)
+            .map_err(|e| WebSocketError::SerializationError(e.to_string()))?;
+
+        self.send(ExperimentMessage::Arguments(value))
+    }
+}
+
+/// Typed access to the configurations and arguments of a run, implemented by the
+/// experiment responses of both the tracel and Station clients.
+pub trait ExperimentConfigExt {
+    /// Configurations logged with [`WebSocketClient::log_config`], by name.
+    fn configurations(&self) -> &HashMap<String, Value>;
+
+    /// Arguments logged with [`WebSocketClient::log_arguments`], as JSON.
+    fn arguments_value(&self) -> &Value;
+
+    /// Deserializes the configuration logged under `name`. Returns `Ok(None)` when
+    /// no configuration with that name was logged.
+    fn config<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>, serde_json::Error> {
+        self.configurations()
+            .get(name)
+            .map(|value| T::deserialize(value))
+            .transpose()
+    }
+
+    /// Infers the schema of the configuration logged under `name`, e.g. to check
+    /// it for drift against another run with [`ConfigSchema::drift`].
+    fn config_schema(&self, name: &str) -> Option<ConfigSchema> {
+        self.configurations().get(name).map(ConfigSchema::infer)
+    }
+
+    /// Deserializes the arguments the run was started with.
+    fn typed_arguments<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(self.arguments_value())
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::{BTreeMap, HashMap};
+
+use thiserror::Error;
+
+use crate::{
+    WebSocketClient,
+    websocket::{ExperimentMessage, MetricLog, WebSocketError},
+};
+
+/// Definition of a metric logged by a run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricDefinition {
+    pub name: String,
+    pub description: Option<String>,
+    pub unit: Option<String>,
+    pub higher_is_better: bool,
+}
+
+#[derive(Error, Debug)]
+pub enum MetricRegistryError {
+    #[error("Metric `{0}` was logged before being defined")]
+    UndefinedMetric(String),
+    #[error("Metric `{0}` was already defined differently")]
+    ConflictingDefinition(String),
+    #[error("Metric `{name}` has a non-finite value: {value}")]
+    NonFiniteValue { name: String, value: f64 },
+    #[error(transparent)]
+    WebSocket(#[from] WebSocketError),
+}
+
+/// Client-side bookkeeping of the metrics of a run.
+///
+/// Definitions are sent once; logged items are checked against them and their best
+/// values are tracked per epoch and split, so the epoch summary can be sent
+/// without recomputing it.
+#[derive(Debug, Default)]
+pub struct MetricRegistry {
+    definitions: HashMap<String, MetricDefinition>,
+    best: HashMap<(usize, String), BTreeMap<String, f64>>,
+}
+
+impl MetricRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn definition(&self, name: &str) -> Option<&MetricDefinition> {
+        self.definitions.get(name)
+    }
+
+    /// Sends a metric definition unless the same definition was already sent.
+    /// Redefining a metric differently, e.g. flipping `higher_is_better`, is an error.
+    pub fn define(
+        &mut self,
+        websocket: &mut WebSocketClient,
+        definition: MetricDefinition,
+    ) -> Result<(), MetricRegistryError> {
+        if let Some(existing) = self.definitions.get(&definition.name) {
+            return if existing == &definition {
+                Ok(())
+            } else {
+                Err(MetricRegistryError::ConflictingDefinition(definition.name))
+            };
+        }
+
+        websocket.send(ExperimentMessage::MetricDefinitionLog {
+            name: definition.name.clone(),
+            description: definition.description.clone(),
+            unit: definition.unit.clone(),
+            higher_is_better: definition.higher_is_better,
+        })?;
+        self.definitions.insert(definition.name.clone(), definition);
+
+        Ok(())
+    }
+
+    /// Validates and sends metric items. Nothing is sent if any item refers to an
+    /// undefined metric or has a NaN or infinite value.
+    pub fn log(
+        &mut self,
+        websocket: &mut WebSocketClient,
+        epoch: usize,
+        split: &str,
+        iteration: usize,
+        items: Vec<MetricLog>,
+    ) -> Result<(), MetricRegistryError> {
+        for item in &items {
+            if !self.definitions.contains_key(&item.name) {
+                return Err(MetricRegistryError::UndefinedMetric(item.name.clone()));
+            }
+            if !item.value.is_finite() {
+                return Err(MetricRegistryError::NonFiniteValue {
+                    name: item.name.clone(),
+                    value: item.value,
+                });
+            }
+        }
+
+        let values = items
+            .iter()
+            .map(|item| (item.name.clone(), item.value))
+            .collect::<Vec<_>>();
+        websocket.send(ExperimentMessage::MetricsLog {
+            epoch,
+            split: split.to_string(),
+            iteration,
+            items,
+        })?;
+
+        // Only values the server received count towards the epoch summary.
+        let best = self.best.entry((epoch, split.to_string())).or_default();
+        for (name, value) in values {
+            let higher_is_better = self.definitions[&name].higher_is_better;
+            best.entry(name)
+                .and_modify(|best| {
+                    let improved = if higher_is_better {
+                        value > *best
+                    } else {
+                        value < *best
+                    };
+                    if improved {
+                        *best = value;
+                    }
+                })
+                .or_insert(value);
+        }
+
+        Ok(())
+    }
+
+    /// Sends the summary of an epoch with the best value of every metric logged
+    /// during it, then forgets them. Sends nothing if no metric was logged.
+    pub fn end_epoch(
+        &mut self,
+        websocket: &mut WebSocketClient,
+        epoch: usize,
+        split: &str,
+    ) -> Result<(), MetricRegistryError> {
+        let Some(best) = self.best.remove(&(epoch, split.to_string())) else {
+            return Ok(());
+        };
+
+        websocket.send(ExperimentMessage::EpochSummaryLog {
+            epoch,
+            split: split.to_string(),
+            best_metric_values: best
+                .into_iter()
+                .map(|(name, value)| MetricLog { name, value })
+                .collect(),
+        })?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
+pub mod config;
+pub mod metrics;
 pub mod request;
 pub mod response;
+pub mod resume;
+pub mod samples;
 pub mod websocket;
 
 use std::collections::HashMap;
@@ -8,10 +12,34 @@ use serde_json::Value;
 
 use crate::{
     Client, ClientError, WebSocketClient,
-    experiment::{request::CreateExperimentSchema, response::ExperimentResponse},
+    experiment::{
+        request::{CreateExperimentSchema, ListExperimentsQuery, MetricAggregatedQuery},
+        response::{
+            ExperimentListResponse, ExperimentResponse, MetricMetadataResponse, MetricResponse,
+            MetricSummaryResponse,
+        },
+    },
     websocket::WebSocketError,
 };
 
+/// Environment variable holding a JSON object of attributes that
+/// [`Client::create_experiment`] adds to every experiment it creates. Jobs started
+/// from a [`JobSpec`](crate::JobSpec) with experiment attributes set it.
+pub const EXPERIMENT_ATTRIBUTES_ENV: &str = "TRACEL_EXPERIMENT_ATTRIBUTES";
+
+/// Attributes from [`EXPERIMENT_ATTRIBUTES_ENV`]. A malformed value is ignored so a
+/// bad environment does not prevent the run from being tracked.
+fn attributes_from_env() -> HashMap<String, Value> {
+    let Ok(raw) = std::env::var(EXPERIMENT_ATTRIBUTES_ENV) else {
+        return HashMap::new();
+    };
+
+    serde_json::from_str(&raw).unwrap_or_else(|e| {
+        tracing::warn!("Ignoring {EXPERIMENT_ATTRIBUTES_ENV}, it is not a JSON object: {e}");
+        HashMap::new()
+    })
+}
+
 impl Client {
     /// Formats a WebSocket URL for the given experiment.
     fn format_websocket_url(&self, owner_name: &str, project_name: &str, exp_num: i32) -> String {
@@ -30,6 +58,9 @@ impl Client {
 
     /// Create a new experiment for the given project.
     ///
+    /// Attributes from the [`EXPERIMENT_ATTRIBUTES_ENV`] environment variable are
+    /// added, with `attributes` taking precedence on conflicting keys.
+    ///
     /// The client must be logged in before calling this method.
     pub fn create_experiment(
         &self,
@@ -39,6 +70,9 @@ impl Client {
         description: Option<String>,
         attributes: HashMap<String, Value>,
     ) -> Result<ExperimentResponse, ClientError> {
+        let mut merged = attributes_from_env();
+        merged.extend(attributes);
+
         let path: &str = &format!("projects/{owner_name}/{project_name}/experiments");
         let url = self.transport.join(path);
 
@@ -48,7 +82,7 @@ impl Client {
             Some(CreateExperimentSchema {
                 name,
                 description,
-                attributes,
+                attributes: merged,
             }),
         )?;
 
@@ -86,4 +120,122 @@ impl Client {
 
         self.transport.post(url, None::<()>)
     }
+
+    /// List the experiments of a project.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_experiments(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        query: ListExperimentsQuery,
+    ) -> Result<ExperimentListResponse, ClientError> {
+        let mut url = self
+            .transport
+            .join(&format!("projects/{owner_name}/{project_name}/experiments"));
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(page) = query.page {
+                pairs.append_pair("page", &page.to_string());
+            }
+            if let Some(per_page) = query.per_page {
+                pairs.append_pair("per_page", &per_page.to_string());
+            }
+            if let Some(filter) = &query.filter {
+                pairs.extend_pairs(filter.query_pairs()?);
+            }
+            if let Some(sort) = &query.sort {
+                pairs.extend_pairs(sort.query_pairs());
+            }
+        }
+
+        self.transport.get_json(url)
+    }
+
+    /// Get details about a specific experiment.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn get_experiment(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        exp_num: i32,
+    ) -> Result<ExperimentResponse, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{owner_name}/{project_name}/experiments/{exp_num}"
+        ))
+    }
+
+    /// Get the most recently created experiment of a project, if any.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn latest_experiment(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+    ) -> Result<Option<ExperimentResponse>, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{owner_name}/{project_name}/experiments/latest"
+        ))
+    }
+
+    /// Get the metric names and groups logged by an experiment.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn experiment_metric_metadata(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        exp_num: i32,
+    ) -> Result<MetricMetadataResponse, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{owner_name}/{project_name}/experiments/{exp_num}/metrics/metadata"
+        ))
+    }
+
+    /// Get the best value of a metric for each group of an experiment.
+    ///
+    /// Returns `None` when the metric has no logged values.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn experiment_metric_summary(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        exp_num: i32,
+        metric: &str,
+    ) -> Result<Option<MetricSummaryResponse>, ClientError> {
+        let mut url = self.transport.join(&format!(
+            "projects/{owner_name}/{project_name}/experiments/{exp_num}/metrics/summary"
+        ));
+        url.query_pairs_mut().append_pair("metric", metric);
+
+        self.transport.get_optional_json(url)
+    }
+
+    /// Get the values of a metric for each group of an experiment.
+    ///
+    /// Returns `None` when the metric has no logged values.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn experiment_metrics(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        exp_num: i32,
+        query: MetricAggregatedQuery,
+    ) -> Result<Option<MetricResponse>, ClientError> {
+        let mut url = self.transport.join(&format!(
+            "projects/{owner_name}/{project_name}/experiments/{exp_num}/metrics"
+        ));
+        url.query_pairs_mut()
+            .append_pair("metric", &query.metric)
+            .append_pair("max_points", &query.max_points.to_string())
+            .append_pair(
+                "downsampling_factor",
+                &query.downsampling_factor.to_string(),
+            );
+
+        self.transport.get_optional_json(url)
+    }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 #[derive(Serialize, Debug, Clone)]
@@ -9,3 +9,126 @@ pub(crate) struct CreateExperimentSchema {
     pub description: Option<String>,
     pub attributes: HashMap<String, Value>,
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ListExperimentsQuery {
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+    #[serde(default)]
+    pub filter: Option<ExperimentFilterRequest>,
+    #[serde(default)]
+    pub sort: Option<ExperimentSortRequest>,
+}
+
+/// Restricts the experiments returned by a listing. Every set criterion must match.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ExperimentFilterRequest {
+    /// Matches experiments whose status is any of these.
+    #[serde(default)]
+    pub status: Vec<String>,
+    /// RFC 3339 timestamp, inclusive.
+    pub created_after: Option<String>,
+    /// RFC 3339 timestamp, exclusive.
+    pub created_before: Option<String>,
+    /// Case-insensitive substring of the experiment name.
+    pub name_contains: Option<String>,
+    #[serde(default)]
+    pub attributes: Vec<ExperimentJsonFilterRequest>,
+    #[serde(default)]
+    pub configurations: Vec<ExperimentJsonFilterRequest>,
+}
+
+impl ExperimentFilterRequest {
+    pub(crate) fn query_pairs(&self) -> Result<Vec<(&'static str, String)>, serde_json::Error> {
+        let mut pairs = Vec::new();
+        if !self.status.is_empty() {
+            pairs.push(("status", self.status.join(",")));
+        }
+        if let Some(created_after) = &self.created_after {
+            pairs.push(("created_after", created_after.clone()));
+        }
+        if let Some(created_before) = &self.created_before {
+            pairs.push(("created_before", created_before.clone()));
+        }
+        if let Some(name_contains) = &self.name_contains {
+            pairs.push(("name_contains", name_contains.clone()));
+        }
+        if !self.attributes.is_empty() {
+            pairs.push(("attributes", serde_json::to_string(&self.attributes)?));
+        }
+        if !self.configurations.is_empty() {
+            pairs.push((
+                "configurations",
+                serde_json::to_string(&self.configurations)?,
+            ));
+        }
+        Ok(pairs)
+    }
+}
+
+/// A condition on the JSON value found at `path` (dot-separated, e.g. `optimizer.lr`)
+/// in an experiment's attributes or configurations.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ExperimentJsonFilterRequest {
+    Equals(ExperimentJsonComparisonRequest),
+    Contains(ExperimentJsonComparisonRequest),
+    GreaterThan(ExperimentJsonComparisonRequest),
+    LessThan(ExperimentJsonComparisonRequest),
+    Exists(ExperimentJsonPathRequest),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ExperimentJsonPathRequest {
+    pub path: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ExperimentJsonComparisonRequest {
+    pub path: String,
+    pub value: Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExperimentSortField {
+    ExperimentNum,
+    Name,
+    Status,
+    CreatedAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ExperimentSortRequest {
+    pub field: ExperimentSortField,
+    #[serde(default)]
+    pub direction: SortDirection,
+}
+
+impl ExperimentSortRequest {
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("sort", self.field.to_string()),
+            ("order", self.direction.to_string()),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MetricAggregatedQuery {
+    pub metric: String,
+    pub max_points: i64,
+    pub downsampling_factor: i64,
+}
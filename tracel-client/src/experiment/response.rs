@@ -3,9 +3,108 @@ use std::collections::HashMap;
 use serde::Deserialize;
 use serde_json::Value;
 
+use crate::experiment::config::ExperimentConfigExt;
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct ExperimentResponse {
     pub experiment_num: i32,
     pub name: Option<String>,
     pub attributes: HashMap<String, Value>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub arguments: Value,
+    #[serde(default)]
+    pub configurations: HashMap<String, Value>,
+    #[serde(default)]
+    pub inputs: Vec<ExperimentInputResponse>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExperimentInputResponse {
+    Artifact {
+        artifact_id: String,
+    },
+    Model {
+        model_version_id: String,
+    },
+    DatasetVersion {
+        dataset_version_id: String,
+    },
+    AnnotationSet {
+        annotation_set_id: String,
+    },
+    CodeVersion {
+        code_version_id: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+impl ExperimentConfigExt for ExperimentResponse {
+    fn configurations(&self) -> &HashMap<String, Value> {
+        &self.configurations
+    }
+
+    fn arguments_value(&self) -> &Value {
+        &self.arguments
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ExperimentListResponse {
+    pub items: Vec<ExperimentResponse>,
+    pub total: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MetricMetadataResponse {
+    pub metric_types: Vec<String>,
+    pub groups: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MetricSummaryGroupResponse {
+    pub group: String,
+    pub optimal_value: f64,
+    pub epoch: usize,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MetricSummaryResponse {
+    pub groups: Vec<MetricSummaryGroupResponse>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MetricEntryResponse {
+    pub epoch: usize,
+    pub iteration: usize,
+    pub value: f64,
+    pub low: f64,
+    pub high: f64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MetricGroupResponse {
+    pub name: String,
+    pub entries: Vec<MetricEntryResponse>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MetricResponse {
+    pub groups: Vec<MetricGroupResponse>,
+}
+
+/// Progress of a run as last recorded by the server.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ExperimentRunStateResponse {
+    pub running: bool,
+    pub last_activity_id: Option<u64>,
+    pub last_epoch: Option<usize>,
+    pub last_iteration: Option<usize>,
 }
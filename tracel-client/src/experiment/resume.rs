@@ -0,0 +1,72 @@
+use thiserror::Error;
+
+use crate::{
+    Client, ClientError, WebSocketClient, experiment::response::ExperimentRunStateResponse,
+    websocket::WebSocketError,
+};
+
+/// A run reattached to an experiment that was started by another process.
+pub struct ResumedExperiment {
+    pub exp_num: i32,
+    /// WebSocket to keep logging into the experiment.
+    pub websocket: WebSocketClient,
+    /// First activity id that was not used by the previous process.
+    pub next_activity_id: u64,
+    pub last_epoch: Option<usize>,
+    pub last_iteration: Option<usize>,
+}
+
+#[derive(Error, Debug)]
+pub enum ResumeExperimentError {
+    #[error("Experiment {0} is not running and cannot be resumed")]
+    NotRunning(i32),
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error(transparent)]
+    WebSocket(#[from] WebSocketError),
+}
+
+impl Client {
+    /// Get the progress last recorded by the server for a run.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn get_experiment_run_state(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        exp_num: i32,
+    ) -> Result<ExperimentRunStateResponse, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{owner_name}/{project_name}/experiments/{exp_num}/run/state"
+        ))
+    }
+
+    /// Continue logging into an experiment whose training process died.
+    ///
+    /// Checks that the experiment is still running, reopens its run WebSocket and
+    /// restores the activity id counter and the last logged epoch and iteration, so
+    /// the new process can pick up where the previous one stopped.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn resume_experiment(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        exp_num: i32,
+    ) -> Result<ResumedExperiment, ResumeExperimentError> {
+        let state = self.get_experiment_run_state(owner_name, project_name, exp_num)?;
+        if !state.running {
+            return Err(ResumeExperimentError::NotRunning(exp_num));
+        }
+
+        let websocket = self.create_experiment_run_websocket(owner_name, project_name, exp_num)?;
+
+        Ok(ResumedExperiment {
+            exp_num,
+            websocket,
+            next_activity_id: state.last_activity_id.map_or(0, |id| id + 1),
+            last_epoch: state.last_epoch,
+            last_iteration: state.last_iteration,
+        })
+    }
+}
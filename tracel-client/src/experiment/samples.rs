@@ -0,0 +1,159 @@
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    Client, ClientError,
+    artifact::request::{ArtifactFileSpecRequest, CreateArtifactRequest},
+};
+
+/// Payloads up to this size are sent inline over the WebSocket; larger ones are
+/// uploaded as an artifact and referenced by id.
+pub const MAX_INLINE_SAMPLE_BYTES: usize = 256 * 1024;
+
+const SAMPLE_ARTIFACT_KIND: &str = "sample";
+
+/// Distribution of a set of values, such as the weights of a layer. Same shape as
+/// the fleet histogram metric: `buckets` holds `(upper_bound, count)` pairs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistogramData {
+    pub count: u64,
+    pub sum: f64,
+    pub buckets: Vec<(f64, u64)>,
+}
+
+impl HistogramData {
+    /// Builds a histogram with `bucket_count` equal-width buckets spanning the finite
+    /// values. Non-finite values are ignored. When all finite values are equal, the
+    /// histogram has a single `(value, count)` bucket.
+    pub fn from_values(values: &[f64], bucket_count: usize) -> Self {
+        let finite = values.iter().copied().filter(|value| value.is_finite());
+        let (min, max) = finite
+            .clone()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+
+        let mut histogram = HistogramData {
+            count: 0,
+            sum: 0.0,
+            buckets: Vec::new(),
+        };
+        if bucket_count == 0 || min > max {
+            return histogram;
+        }
+
+        let width = (max - min) / bucket_count as f64;
+        histogram.buckets = if width > 0.0 {
+            (1..=bucket_count)
+                .map(|bucket| (min + width * bucket as f64, 0))
+                .collect()
+        } else {
+            vec![(min, 0)]
+        };
+        for value in finite {
+            let bucket = if width > 0.0 {
+                (((value - min) / width) as usize).min(bucket_count - 1)
+            } else {
+                0
+            };
+            histogram.buckets[bucket].1 += 1;
+            histogram.count += 1;
+            histogram.sum += value;
+        }
+
+        histogram
+    }
+}
+
+/// Confusion matrix of a classifier: `counts[actual][predicted]`, indexed like
+/// `labels`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfusionMatrixData {
+    pub labels: Vec<String>,
+    pub counts: Vec<Vec<u64>>,
+}
+
+/// Binary content of a logged sample, either carried by the message or stored in
+/// an artifact of the experiment.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SamplePayload {
+    Inline {
+        content_type: String,
+        #[serde_as(as = "serde_with::base64::Base64")]
+        data: Vec<u8>,
+    },
+    Artifact {
+        content_type: String,
+        artifact_id: String,
+        rel_path: String,
+    },
+}
+
+impl Client {
+    /// Prepare the payload of a sample for logging: small payloads are kept inline,
+    /// larger ones are uploaded as a `sample` artifact named `name`.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn sample_payload(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        exp_num: i32,
+        name: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<SamplePayload, ClientError> {
+        if data.len() <= MAX_INLINE_SAMPLE_BYTES {
+            return Ok(SamplePayload::Inline {
+                content_type: content_type.to_string(),
+                data,
+            });
+        }
+
+        let rel_path = name.to_string();
+        let artifact = self.create_artifact(
+            owner_name,
+            project_name,
+            exp_num,
+            CreateArtifactRequest {
+                name: name.to_string(),
+                kind: SAMPLE_ARTIFACT_KIND.to_string(),
+                files: vec![ArtifactFileSpecRequest {
+                    rel_path: rel_path.clone(),
+                    size_bytes: data.len() as u64,
+                    checksum: format!("{:x}", Sha256::digest(&data)),
+                }],
+            },
+        )?;
+
+        let mut parts = artifact
+            .files
+            .iter()
+            .flat_map(|file| &file.urls.parts)
+            .collect::<Vec<_>>();
+        parts.sort_by_key(|part| part.part);
+        let covered = parts.iter().map(|part| part.size_bytes).sum::<u64>();
+        if covered != data.len() as u64 {
+            return Err(ClientError::UnknownError(format!(
+                "Upload parts of sample `{name}` cover {covered} bytes, expected {}",
+                data.len()
+            )));
+        }
+
+        let mut offset = 0;
+        for part in parts {
+            let end = offset + part.size_bytes as usize;
+            self.upload_bytes_to_url(&part.url, data[offset..end].to_vec())?;
+            offset = end;
+        }
+        self.complete_artifact_upload(owner_name, project_name, exp_num, &artifact.id, None)?;
+
+        Ok(SamplePayload::Artifact {
+            content_type: content_type.to_string(),
+            artifact_id: artifact.id,
+            rel_path,
+        })
+    }
+}
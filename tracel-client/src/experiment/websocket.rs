@@ -1,5 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+pub use crate::experiment::config::{
+    ConfigDrift, ConfigDriftKind, ConfigSchema, ExperimentConfigExt,
+};
+pub use crate::experiment::metrics::{MetricDefinition, MetricRegistry, MetricRegistryError};
+pub use crate::experiment::samples::{
+    ConfusionMatrixData, HistogramData, MAX_INLINE_SAMPLE_BYTES, SamplePayload,
+};
+
 #[derive(Debug, Serialize)]
 pub struct ActivityMeterRequest {
     pub unit: Option<String>,
@@ -47,8 +55,22 @@ pub enum ActivityEventRequest {
 #[derive(Debug, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum InputUsed {
-    Artifact { artifact_id: String },
-    Model { model_version_id: String },
+    Artifact {
+        artifact_id: String,
+    },
+    Model {
+        model_version_id: String,
+    },
+    DatasetVersion {
+        dataset_version_id: String,
+    },
+    AnnotationSet {
+        annotation_set_id: String,
+    },
+    /// A project code version, identified by the `id` returned when publishing it.
+    CodeVersion {
+        code_version_id: String,
+    },
 }
 
 #[derive(Debug, Serialize)]
@@ -84,12 +106,41 @@ pub enum ExperimentMessage {
         split: String,
         best_metric_values: Vec<MetricLog>,
     },
+    HistogramLog {
+        epoch: usize,
+        split: String,
+        iteration: usize,
+        name: String,
+        histogram: HistogramData,
+    },
+    ConfusionMatrixLog {
+        epoch: usize,
+        split: String,
+        name: String,
+        matrix: ConfusionMatrixData,
+    },
+    ImageLog {
+        epoch: usize,
+        split: String,
+        name: String,
+        image: SamplePayload,
+    },
+    TextLog {
+        epoch: usize,
+        split: String,
+        name: String,
+        text: String,
+    },
     Log(String),
     Arguments(serde_json::Value),
     Config {
         value: serde_json::Value,
         name: String,
     },
+    ConfigSchema {
+        name: String,
+        schema: ConfigSchema,
+    },
     Attribute {
         key: String,
         value: serde_json::Value,
@@ -1,8 +1,85 @@
 pub mod request;
+pub mod response;
+pub mod spec;
+pub mod sweep;
 
-use crate::{Client, ClientError, job::request::ComputeProviderQueueJobRequest};
+use std::collections::BTreeMap;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{
+    Client, ClientError,
+    job::{
+        request::{ComputeProviderQueueJobRequest, ListJobsQuery},
+        response::{JobListResponse, JobLogsResponse, JobResponse, QueuedJobResponse},
+        spec::JobSpec,
+    },
+};
+
+const WAIT_INITIAL_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const WAIT_MAX_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A job queued on a compute provider group, returned by
+/// [`Client::start_remote_job`].
+#[derive(Debug, Clone)]
+pub struct JobHandle {
+    pub id: String,
+    pub owner_name: String,
+    pub project_name: String,
+}
+
+impl JobHandle {
+    pub fn status(&self, client: &Client) -> Result<JobResponse, ClientError> {
+        client.get_job(&self.owner_name, &self.project_name, &self.id)
+    }
+
+    pub fn cancel(&self, client: &Client) -> Result<(), ClientError> {
+        client.cancel_job(&self.owner_name, &self.project_name, &self.id)
+    }
+
+    pub fn logs(&self, client: &Client) -> Result<JobLogsResponse, ClientError> {
+        client.job_logs(&self.owner_name, &self.project_name, &self.id)
+    }
+
+    /// Blocks until the job reaches a terminal status, polling with exponential
+    /// backoff. Fails with [`ClientError::Timeout`] if it is still running after
+    /// `timeout`. A timeout too large to be represented, such as [`Duration::MAX`],
+    /// waits indefinitely.
+    pub fn wait(&self, client: &Client, timeout: Duration) -> Result<JobResponse, ClientError> {
+        let deadline = Instant::now().checked_add(timeout);
+        let mut interval = WAIT_INITIAL_POLL_INTERVAL;
+
+        loop {
+            let job = self.status(client)?;
+            if job.status.is_terminal() {
+                return Ok(job);
+            }
+
+            let mut sleep = interval;
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(ClientError::Timeout(format!(
+                        "job {} is still {} after {timeout:?}",
+                        self.id, job.status
+                    )));
+                }
+                sleep = sleep.min(deadline - now);
+            }
+
+            thread::sleep(sleep);
+            interval = (interval * 2).min(WAIT_MAX_POLL_INTERVAL);
+        }
+    }
+}
 
 impl Client {
+    /// Queue a job running `command` on the code version `digest`.
+    ///
+    /// See [`start_job`](Self::start_job) to pass arguments, environment and resources
+    /// without building a shell command line.
+    ///
+    /// The client must be logged in before calling this method.
     pub fn start_remote_job(
         &self,
         compute_provider_group_name: &str,
@@ -10,16 +87,121 @@ impl Client {
         project_name: &str,
         digest: &str,
         command: &str,
-    ) -> Result<(), ClientError> {
-        let path: &str = &format!("projects/{owner_name}/{project_name}/jobs/queue");
-        let url = self.transport.join(path);
-
+    ) -> Result<JobHandle, ClientError> {
         let body = ComputeProviderQueueJobRequest {
             compute_provider_group_name: compute_provider_group_name.to_string(),
             digest: digest.to_string(),
             command: command.to_string(),
+            args: Vec::new(),
+            env: BTreeMap::new(),
+            secrets: Vec::new(),
+            resources: None,
+            timeout_seconds: None,
+            max_retries: None,
+            experiment_name: None,
         };
 
-        self.transport.post(url, Some(body))
+        self.queue_job(owner_name, project_name, body)
+    }
+
+    /// Queue a job described by a [`JobSpec`].
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn start_job(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        spec: JobSpec,
+    ) -> Result<JobHandle, ClientError> {
+        self.queue_job(owner_name, project_name, spec.into_request()?)
+    }
+
+    fn queue_job(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        body: ComputeProviderQueueJobRequest,
+    ) -> Result<JobHandle, ClientError> {
+        let path: &str = &format!("projects/{owner_name}/{project_name}/jobs/queue");
+        let url = self.transport.join(path);
+
+        let response: QueuedJobResponse = self.transport.post_json(url, Some(body))?;
+
+        Ok(JobHandle {
+            id: response.id,
+            owner_name: owner_name.to_string(),
+            project_name: project_name.to_string(),
+        })
+    }
+
+    /// Get details about a specific job.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn get_job(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        job_id: &str,
+    ) -> Result<JobResponse, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{owner_name}/{project_name}/jobs/{job_id}"
+        ))
+    }
+
+    /// List the jobs of a project.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_jobs(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        query: ListJobsQuery,
+    ) -> Result<JobListResponse, ClientError> {
+        let mut url = self
+            .transport
+            .join(&format!("projects/{owner_name}/{project_name}/jobs"));
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(page) = query.page {
+                pairs.append_pair("page", &page.to_string());
+            }
+            if let Some(per_page) = query.per_page {
+                pairs.append_pair("per_page", &per_page.to_string());
+            }
+            if let Some(status) = query.status {
+                pairs.append_pair("status", &status.to_string());
+            }
+        }
+
+        self.transport.get_json(url)
+    }
+
+    /// Cancel a queued or running job.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn cancel_job(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        job_id: &str,
+    ) -> Result<(), ClientError> {
+        self.transport.post(
+            format!("projects/{owner_name}/{project_name}/jobs/{job_id}/cancel"),
+            None::<()>,
+        )
+    }
+
+    /// Get the logs of a job.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn job_logs(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        job_id: &str,
+    ) -> Result<JobLogsResponse, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{owner_name}/{project_name}/jobs/{job_id}/logs"
+        ))
     }
 }
@@ -1,8 +1,60 @@
+use std::collections::BTreeMap;
+
 use serde::Serialize;
 
+use crate::job::response::JobStatus;
+
+/// Body of `POST /projects/{owner}/{project}/jobs/queue`. Only `command` was sent by
+/// older clients; every other field is omitted when unset so such bodies keep their
+/// original shape.
 #[derive(Debug, Serialize, Clone)]
 pub(crate) struct ComputeProviderQueueJobRequest {
     pub compute_provider_group_name: String,
     pub digest: String,
     pub command: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub secrets: Vec<JobSecretRequest>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<JobResourcesRequest>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experiment_name: Option<String>,
+}
+
+/// Exposes the project secret `secret` to the job as the environment variable `env`.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct JobSecretRequest {
+    pub env: String,
+    pub secret: String,
+}
+
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+pub struct JobResourcesRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_mb: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub accelerators: Vec<AcceleratorRequest>,
+}
+
+/// A number of accelerators of one kind, e.g. `{ kind: "nvidia-a100", count: 2 }`.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct AcceleratorRequest {
+    pub kind: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ListJobsQuery {
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+    pub status: Option<JobStatus>,
 }
@@ -0,0 +1,58 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct QueuedJobResponse {
+    pub id: String,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[non_exhaustive]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+    #[serde(other)]
+    Unknown,
+}
+
+impl JobStatus {
+    /// Whether the job has stopped and will not change status anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Succeeded | JobStatus::Failed | JobStatus::Cancelled
+        )
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct JobResponse {
+    pub id: String,
+    pub status: JobStatus,
+    pub compute_provider_group_name: String,
+    /// Compute provider the job was dispatched to, once it has been picked up.
+    pub compute_provider_name: Option<String>,
+    pub digest: String,
+    pub command: String,
+    /// Experiment created by the job, once it has started one.
+    pub experiment_num: Option<i32>,
+    pub failure_reason: Option<String>,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct JobListResponse {
+    pub items: Vec<JobResponse>,
+    pub total: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct JobLogsResponse {
+    pub logs: Vec<String>,
+}
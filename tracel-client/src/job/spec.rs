@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::{
+    ClientError,
+    experiment::EXPERIMENT_ATTRIBUTES_ENV,
+    job::request::{
+        AcceleratorRequest, ComputeProviderQueueJobRequest, JobResourcesRequest, JobSecretRequest,
+    },
+};
+
+/// Description of a remote job, queued with [`Client::start_job`](crate::Client::start_job).
+///
+/// The program and its arguments are kept as separate argv entries; no shell
+/// escaping is needed.
+#[derive(Debug, Clone)]
+pub struct JobSpec {
+    compute_provider_group_name: String,
+    digest: String,
+    program: String,
+    args: Vec<String>,
+    env: BTreeMap<String, String>,
+    secrets: Vec<JobSecretRequest>,
+    resources: JobResourcesRequest,
+    timeout: Option<Duration>,
+    max_retries: Option<u32>,
+    experiment_name: Option<String>,
+    experiment_attributes: BTreeMap<String, Value>,
+}
+
+impl JobSpec {
+    /// Runs `program` from the code version `digest` on the given compute provider group.
+    pub fn new(
+        compute_provider_group_name: impl Into<String>,
+        digest: impl Into<String>,
+        program: impl Into<String>,
+    ) -> Self {
+        Self {
+            compute_provider_group_name: compute_provider_group_name.into(),
+            digest: digest.into(),
+            program: program.into(),
+            args: Vec::new(),
+            env: BTreeMap::new(),
+            secrets: Vec::new(),
+            resources: JobResourcesRequest::default(),
+            timeout: None,
+            max_retries: None,
+            experiment_name: None,
+            experiment_attributes: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Exposes the project secret `secret` as the environment variable `env`. The
+    /// secret value never goes through this client.
+    pub fn with_secret(mut self, env: impl Into<String>, secret: impl Into<String>) -> Self {
+        self.secrets.push(JobSecretRequest {
+            env: env.into(),
+            secret: secret.into(),
+        });
+        self
+    }
+
+    pub fn with_cpus(mut self, cpus: f32) -> Self {
+        self.resources.cpus = Some(cpus);
+        self
+    }
+
+    pub fn with_memory_mb(mut self, memory_mb: u64) -> Self {
+        self.resources.memory_mb = Some(memory_mb);
+        self
+    }
+
+    pub fn with_accelerators(mut self, kind: impl Into<String>, count: u32) -> Self {
+        self.resources.accelerators.push(AcceleratorRequest {
+            kind: kind.into(),
+            count,
+        });
+        self
+    }
+
+    /// Maximum run time of one attempt. Timeouts are sent in whole seconds, rounded
+    /// up, so a sub-second timeout still allows one second, and capped at
+    /// `u64::MAX` seconds, so [`Duration::MAX`] is sent as the largest timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Number of times a failed attempt is retried.
+    pub fn with_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Name of the experiment created by the job. `{job_id}` and `{attempt}` are
+    /// replaced by the server.
+    pub fn with_experiment_name(mut self, template: impl Into<String>) -> Self {
+        self.experiment_name = Some(template.into());
+        self
+    }
+
+    /// Sets an attribute on the experiments created by the job. Attributes are passed
+    /// to the job as JSON in the `TRACEL_EXPERIMENT_ATTRIBUTES` environment variable,
+    /// which [`Client::create_experiment`](crate::Client::create_experiment) reads.
+    ///
+    /// If that variable is also set with [`with_env`](Self::with_env), it must hold
+    /// a JSON object; both are merged, and attributes set here win on shared keys.
+    pub fn with_experiment_attribute(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.experiment_attributes.insert(key.into(), value);
+        self
+    }
+
+    /// The argv as a single shell command line, as sent to servers that only read
+    /// `command`.
+    pub fn command_line(&self) -> String {
+        std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(|arg| shell_quote(arg))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub(crate) fn into_request(mut self) -> Result<ComputeProviderQueueJobRequest, ClientError> {
+        if !self.experiment_attributes.is_empty() {
+            let mut attributes = match self.env.get(EXPERIMENT_ATTRIBUTES_ENV) {
+                Some(raw) => serde_json::from_str::<BTreeMap<String, Value>>(raw).map_err(|e| {
+                    ClientError::InvalidRequest(format!(
+                        "{EXPERIMENT_ATTRIBUTES_ENV} must be a JSON object to be merged with experiment attributes: {e}"
+                    ))
+                })?,
+                None => BTreeMap::new(),
+            };
+            attributes.append(&mut self.experiment_attributes);
+            self.env.insert(
+                EXPERIMENT_ATTRIBUTES_ENV.to_string(),
+                serde_json::to_string(&attributes).expect("JSON values should serialize"),
+            );
+        }
+
+        let command = self.command_line();
+        let resources =
+            (self.resources != JobResourcesRequest::default()).then_some(self.resources);
+
+        Ok(ComputeProviderQueueJobRequest {
+            compute_provider_group_name: self.compute_provider_group_name,
+            digest: self.digest,
+            command,
+            args: std::iter::once(self.program).chain(self.args).collect(),
+            env: self.env,
+            secrets: self.secrets,
+            resources,
+            timeout_seconds: self.timeout.map(|timeout| {
+                timeout
+                    .as_secs()
+                    .saturating_add(u64::from(timeout.subsec_nanos() > 0))
+            }),
+            max_retries: self.max_retries,
+            experiment_name: self.experiment_name,
+        })
+    }
+}
+
+/// Quotes an argument for a POSIX shell when it contains anything but safe characters.
+fn shell_quote(arg: &str) -> String {
+    let is_safe = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c));
+    if is_safe {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
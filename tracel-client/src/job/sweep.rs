@@ -0,0 +1,374 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::{
+    Client, ClientError,
+    job::{JobHandle, response::JobStatus, spec::JobSpec},
+};
+
+const DEFAULT_SWEEP_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Values a sweep parameter can take.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterDistribution {
+    /// One of the listed values. The only distribution a grid search accepts.
+    Choice(Vec<Value>),
+    /// A float drawn uniformly in `[low, high)`.
+    Uniform { low: f64, high: f64 },
+    /// A float whose logarithm is drawn uniformly, for scale parameters such as
+    /// learning rates. Both bounds must be positive.
+    LogUniform { low: f64, high: f64 },
+    /// An integer drawn uniformly in `[low, high]`.
+    IntUniform { low: i64, high: i64 },
+}
+
+/// How a sweep picks parameter sets from the space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SweepStrategy {
+    /// Every combination of the `Choice` values.
+    Grid,
+    /// `trials` independent draws. The same seed yields the same parameter sets.
+    Random { trials: usize, seed: u64 },
+}
+
+/// One assignment of a value to every sweep parameter.
+///
+/// Displays as `name=value` pairs sorted by name, e.g. `batch_size=32,lr=0.001`.
+/// The display form is for output only: it does not quote strings, so distinct
+/// sets can display the same way.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ParameterSet(pub BTreeMap<String, Value>);
+
+impl ParameterSet {
+    /// The set as a JSON object, which is the key of the run in [`SweepSummary::runs`].
+    pub fn key(&self) -> String {
+        serde_json::to_string(&self.0).expect("JSON values should serialize")
+    }
+}
+
+impl Display for ParameterSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, (name, value)) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{name}={}", argument_value(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// A hyperparameter search run as remote jobs.
+///
+/// Every parameter set becomes one job built from `job`, with `--name=value`
+/// appended to its arguments and the parameters set as attributes of the
+/// experiments the job creates, see [`JobSpec::with_experiment_attribute`].
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    job: JobSpec,
+    parameters: BTreeMap<String, ParameterDistribution>,
+    strategy: SweepStrategy,
+    max_concurrent: usize,
+    poll_interval: Duration,
+}
+
+impl Sweep {
+    pub fn new(job: JobSpec, strategy: SweepStrategy) -> Self {
+        Self {
+            job,
+            parameters: BTreeMap::new(),
+            strategy,
+            max_concurrent: 1,
+            poll_interval: DEFAULT_SWEEP_POLL_INTERVAL,
+        }
+    }
+
+    pub fn with_parameter(
+        mut self,
+        name: impl Into<String>,
+        distribution: ParameterDistribution,
+    ) -> Self {
+        self.parameters.insert(name.into(), distribution);
+        self
+    }
+
+    /// Maximum number of jobs queued or running at the same time.
+    pub fn with_max_concurrent(mut self, max_concurrent: usize) -> Self {
+        self.max_concurrent = max_concurrent.max(1);
+        self
+    }
+
+    /// Delay between two checks of the running jobs.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Expands the parameter space into the parameter sets the sweep will run.
+    ///
+    /// Each set is run once: repeated grid values and repeated random draws are
+    /// dropped, so a random sweep over a small discrete space may run fewer than
+    /// `trials` jobs.
+    pub fn parameter_sets(&self) -> Result<Vec<ParameterSet>, ClientError> {
+        for (name, distribution) in &self.parameters {
+            validate(name, distribution)?;
+        }
+
+        let mut seen = HashSet::new();
+        let mut sets = self.expand()?;
+        sets.retain(|set| seen.insert(set.key()));
+        Ok(sets)
+    }
+
+    fn expand(&self) -> Result<Vec<ParameterSet>, ClientError> {
+        match self.strategy {
+            SweepStrategy::Grid => self.grid(),
+            SweepStrategy::Random { trials, seed } => {
+                let mut rng = SplitMix64(seed);
+                Ok((0..trials)
+                    .map(|_| {
+                        ParameterSet(
+                            self.parameters
+                                .iter()
+                                .map(|(name, distribution)| {
+                                    (name.clone(), sample(distribution, &mut rng))
+                                })
+                                .collect(),
+                        )
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    fn grid(&self) -> Result<Vec<ParameterSet>, ClientError> {
+        let mut sets = vec![ParameterSet::default()];
+        for (name, distribution) in &self.parameters {
+            let ParameterDistribution::Choice(values) = distribution else {
+                return Err(ClientError::InvalidRequest(format!(
+                    "grid sweeps only accept choice parameters, `{name}` is continuous"
+                )));
+            };
+
+            sets = sets
+                .into_iter()
+                .flat_map(|set| {
+                    values.iter().map(move |value| {
+                        let mut set = set.clone();
+                        set.0.insert(name.clone(), value.clone());
+                        set
+                    })
+                })
+                .collect();
+        }
+        Ok(sets)
+    }
+
+    fn job_for(&self, parameters: &ParameterSet) -> JobSpec {
+        parameters
+            .0
+            .iter()
+            .fold(self.job.clone(), |job, (name, value)| {
+                job.with_arg(format!("--{name}={}", argument_value(value)))
+                    .with_experiment_attribute(name.clone(), value.clone())
+            })
+    }
+}
+
+/// Outcome of one parameter set of a sweep.
+#[derive(Debug, Clone)]
+pub struct SweepRun {
+    pub parameters: ParameterSet,
+    pub job_id: String,
+    pub status: JobStatus,
+    pub experiment_num: Option<i32>,
+}
+
+/// Outcome of a sweep, keyed by [`ParameterSet::key`].
+#[derive(Debug, Clone, Default)]
+pub struct SweepSummary {
+    pub runs: BTreeMap<String, SweepRun>,
+}
+
+impl SweepSummary {
+    pub fn get(&self, parameters: &ParameterSet) -> Option<&SweepRun> {
+        self.runs.get(&parameters.key())
+    }
+
+    pub fn succeeded(&self) -> impl Iterator<Item = &SweepRun> {
+        self.runs
+            .values()
+            .filter(|run| run.status == JobStatus::Succeeded)
+    }
+}
+
+/// A sweep that stopped on an error. Runs that finished before it are kept in
+/// `summary`; jobs still queued or running are left untouched and returned in
+/// `in_flight`, so they can be waited on or cancelled.
+#[derive(Error, Debug)]
+#[error("Sweep stopped after {} finished runs: {source}", summary.runs.len())]
+pub struct SweepError {
+    #[source]
+    pub source: ClientError,
+    pub summary: SweepSummary,
+    pub in_flight: Vec<(ParameterSet, JobHandle)>,
+}
+
+impl Client {
+    /// Run every parameter set of a sweep as a remote job, keeping at most
+    /// `max_concurrent` jobs in flight, and block until all of them have finished.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn run_sweep(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        sweep: &Sweep,
+    ) -> Result<SweepSummary, SweepError> {
+        let mut summary = SweepSummary::default();
+        let mut in_flight: HashMap<String, (ParameterSet, JobHandle)> = HashMap::new();
+
+        let result = self.drive_sweep(
+            owner_name,
+            project_name,
+            sweep,
+            &mut summary,
+            &mut in_flight,
+        );
+
+        match result {
+            Ok(()) => Ok(summary),
+            Err(source) => Err(SweepError {
+                source,
+                summary,
+                in_flight: in_flight.into_values().collect(),
+            }),
+        }
+    }
+
+    fn drive_sweep(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        sweep: &Sweep,
+        summary: &mut SweepSummary,
+        in_flight: &mut HashMap<String, (ParameterSet, JobHandle)>,
+    ) -> Result<(), ClientError> {
+        let mut pending = sweep.parameter_sets()?.into_iter();
+
+        loop {
+            while in_flight.len() < sweep.max_concurrent {
+                let Some(parameters) = pending.next() else {
+                    break;
+                };
+                let handle =
+                    self.start_job(owner_name, project_name, sweep.job_for(&parameters))?;
+                in_flight.insert(handle.id.clone(), (parameters, handle));
+            }
+
+            if in_flight.is_empty() {
+                return Ok(());
+            }
+
+            thread::sleep(sweep.poll_interval);
+
+            let mut finished = Vec::new();
+            for (id, (_, handle)) in in_flight.iter() {
+                let job = handle.status(self)?;
+                if job.status.is_terminal() {
+                    finished.push((id.clone(), job));
+                }
+            }
+            for (id, job) in finished {
+                let (parameters, _) = in_flight
+                    .remove(&id)
+                    .expect("Finished job should be in flight");
+                summary.runs.insert(
+                    parameters.key(),
+                    SweepRun {
+                        parameters,
+                        job_id: job.id,
+                        status: job.status,
+                        experiment_num: job.experiment_num,
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn validate(name: &str, distribution: &ParameterDistribution) -> Result<(), ClientError> {
+    let invalid = |reason: &str| {
+        Err(ClientError::InvalidRequest(format!(
+            "sweep parameter `{name}` {reason}"
+        )))
+    };
+    let ordered = |low: f64, high: f64| low.is_finite() && high.is_finite() && low < high;
+
+    match *distribution {
+        ParameterDistribution::Choice(ref values) if values.is_empty() => {
+            invalid("has no values to choose from")
+        }
+        ParameterDistribution::Uniform { low, high } if !ordered(low, high) => {
+            invalid("must have finite bounds with the lower one below the upper one")
+        }
+        ParameterDistribution::LogUniform { low, high } if !(low > 0.0 && ordered(low, high)) => {
+            invalid("must have finite positive bounds with the lower one below the upper one")
+        }
+        ParameterDistribution::IntUniform { low, high } if low > high => {
+            invalid("must have a lower bound not above its upper bound")
+        }
+        _ => Ok(()),
+    }
+}
+
+fn sample(distribution: &ParameterDistribution, rng: &mut SplitMix64) -> Value {
+    match *distribution {
+        ParameterDistribution::Choice(ref values) => {
+            values[(rng.next_u64() % values.len() as u64) as usize].clone()
+        }
+        ParameterDistribution::Uniform { low, high } => {
+            Value::from(low + (high - low) * rng.next_f64())
+        }
+        ParameterDistribution::LogUniform { low, high } => {
+            let (low, high) = (low.ln(), high.ln());
+            Value::from((low + (high - low) * rng.next_f64()).exp())
+        }
+        ParameterDistribution::IntUniform { low, high } => {
+            let span = high.abs_diff(low).saturating_add(1);
+            Value::from(low.wrapping_add((rng.next_u64() % span) as i64))
+        }
+    }
+}
+
+/// Renders a parameter value as a command line argument, without quotes around strings.
+fn argument_value(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Small seedable generator, so random sweeps are reproducible without a
+/// dependency on `rand`.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -1,3 +1,4 @@
+mod api_key;
 mod artifact;
 mod client;
 mod credentials;
@@ -5,6 +6,7 @@ mod error;
 mod experiment;
 mod job;
 mod model;
+mod organization;
 mod project;
 mod transport;
 mod user;
@@ -25,17 +27,25 @@ mod tracel {
     pub use fleet::FleetClient;
 
     pub mod response {
+        pub use crate::api_key::response::*;
         pub use crate::artifact::response::*;
         pub use crate::experiment::response::*;
         pub use crate::fleet::response::*;
+        pub use crate::job::response::*;
         pub use crate::model::response::*;
+        pub use crate::organization::response::*;
         pub use crate::project::response::*;
         pub use crate::user::response::*;
     }
 
     pub mod request {
+        pub use crate::api_key::request::*;
         pub use crate::artifact::request::*;
+        pub use crate::experiment::request::*;
         pub use crate::fleet::request::*;
+        pub use crate::job::request::*;
+        pub use crate::model::request::*;
+        pub use crate::organization::request::*;
         pub use crate::project::request::*;
     }
 }
@@ -45,8 +55,32 @@ pub use tracel::*;
 
 #[cfg(feature = "tracel")]
 pub use client::Client;
+#[cfg(feature = "tracel")]
+pub use experiment::EXPERIMENT_ATTRIBUTES_ENV;
+
+#[cfg(feature = "tracel")]
+pub use experiment::resume::{ResumeExperimentError, ResumedExperiment};
+#[cfg(feature = "tracel")]
+pub use job::{
+    JobHandle,
+    spec::JobSpec,
+    sweep::{
+        ParameterDistribution, ParameterSet, Sweep, SweepError, SweepRun, SweepStrategy,
+        SweepSummary,
+    },
+};
+#[cfg(feature = "tracel")]
+pub use model::lineage::ModelLineage;
+#[cfg(feature = "source-archive")]
+pub use project::publish::SourceArchive;
+#[cfg(feature = "tracel")]
+pub use project::publish::{PublishCodeError, PublishedCodeVersion};
 
 pub use client::Env;
-pub use error::ClientError;
+pub use error::{ApiErrorCode, ClientError};
+pub use experiment::config::ExperimentConfigExt;
+pub use model::reference::{
+    CustomModelAlias, ModelAlias, ModelRef, ModelRefParseError, ModelVersionSelector,
+};
 
 pub use websocket::WebSocketClient;
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use crate::{ClientError, experiment::response::ExperimentInputResponse};
+
+/// Where a model version comes from: the experiment that produced it, the inputs
+/// that experiment recorded and, for inputs that are model versions, their own
+/// lineage.
+#[derive(Debug, Clone)]
+pub struct ModelLineage {
+    pub model_version_id: String,
+    pub version: u32,
+    /// `None` when the version was not produced by an experiment.
+    pub experiment_num: Option<i32>,
+    pub inputs: Vec<ExperimentInputResponse>,
+    /// Lineage of every `Model` input, in the order of `inputs`. A model version
+    /// reached twice is only walked the first time, so shared ancestors appear once.
+    pub upstream: Vec<ModelLineage>,
+}
+
+/// The part of a model version response the lineage walk needs.
+pub(crate) struct LineageVersion {
+    pub id: String,
+    pub version: u32,
+    pub experiment_num: Option<i32>,
+}
+
+impl ModelLineage {
+    /// Walks back from `start` through experiments and the model versions they used.
+    pub(crate) fn walk(
+        start: LineageVersion,
+        mut model_version: impl FnMut(&str) -> Result<LineageVersion, ClientError>,
+        mut experiment_inputs: impl FnMut(i32) -> Result<Vec<ExperimentInputResponse>, ClientError>,
+    ) -> Result<Self, ClientError> {
+        let mut visited = HashSet::from([start.id.clone()]);
+        Self::walk_from(
+            start,
+            &mut model_version,
+            &mut experiment_inputs,
+            &mut visited,
+        )
+    }
+
+    fn walk_from(
+        version: LineageVersion,
+        model_version: &mut impl FnMut(&str) -> Result<LineageVersion, ClientError>,
+        experiment_inputs: &mut impl FnMut(i32) -> Result<Vec<ExperimentInputResponse>, ClientError>,
+        visited: &mut HashSet<String>,
+    ) -> Result<Self, ClientError> {
+        let inputs = match version.experiment_num {
+            Some(experiment_num) => experiment_inputs(experiment_num)?,
+            None => Vec::new(),
+        };
+
+        let mut upstream = Vec::new();
+        for input in &inputs {
+            let ExperimentInputResponse::Model { model_version_id } = input else {
+                continue;
+            };
+            if visited.insert(model_version_id.clone()) {
+                let parent = model_version(model_version_id)?;
+                upstream.push(Self::walk_from(
+                    parent,
+                    model_version,
+                    experiment_inputs,
+                    visited,
+                )?);
+            }
+        }
+
+        Ok(ModelLineage {
+            model_version_id: version.id,
+            version: version.version,
+            experiment_num: version.experiment_num,
+            inputs,
+            upstream,
+        })
+    }
+}
@@ -1,11 +1,58 @@
+pub mod lineage;
+pub mod reference;
+pub mod request;
 pub mod response;
 
 use crate::{
     Client, ClientError,
-    model::response::{ModelDownloadResponse, ModelResponse, ModelVersionResponse},
+    artifact::request::CompleteUploadRequest,
+    model::{
+        lineage::{LineageVersion, ModelLineage},
+        reference::{ModelAlias, ModelVersionSelector},
+        request::{
+            CreateModelRequest, CreateModelVersionRequest, ModelFileSpecRequest,
+            SetModelAliasRequest,
+        },
+        response::{
+            ModelAliasListResponse, ModelAliasResponse, ModelDownloadResponse, ModelListResponse,
+            ModelResponse, ModelVersionCreationResponse, ModelVersionListResponse,
+            ModelVersionResponse,
+        },
+    },
 };
 
 impl Client {
+    /// List the models of a project.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_models(
+        &self,
+        namespace: &str,
+        project_name: &str,
+    ) -> Result<ModelListResponse, ClientError> {
+        self.transport
+            .get_json(format!("projects/{namespace}/{project_name}/models"))
+    }
+
+    /// Create a new model in a project.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn create_model(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_name: &str,
+        description: Option<&str>,
+    ) -> Result<ModelResponse, ClientError> {
+        self.transport.post_json(
+            format!("projects/{namespace}/{project_name}/models"),
+            Some(CreateModelRequest {
+                name: model_name.to_string(),
+                description: description.map(|desc| desc.to_string()),
+            }),
+        )
+    }
+
     /// Get details about a specific model.
     ///
     /// The client must be logged in before calling this method.
@@ -20,7 +67,22 @@ impl Client {
         ))
     }
 
-    /// Get details about a specific model version.
+    /// List the versions of a model.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_model_versions(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_name: &str,
+    ) -> Result<ModelVersionListResponse, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{namespace}/{project_name}/models/{model_name}/versions"
+        ))
+    }
+
+    /// Get details about a specific model version, selected by number or through an
+    /// alias. For a [`ModelRef`](reference::ModelRef), pass its `model` and `selector`.
     ///
     /// The client must be logged in before calling this method.
     pub fn get_model_version(
@@ -28,14 +90,16 @@ impl Client {
         namespace: &str,
         project_name: &str,
         model_name: &str,
-        version: u32,
+        version: impl Into<ModelVersionSelector>,
     ) -> Result<ModelVersionResponse, ClientError> {
         self.transport.get_json(format!(
-            "projects/{namespace}/{project_name}/models/{model_name}/versions/{version}"
+            "projects/{namespace}/{project_name}/models/{model_name}/{}",
+            version.into().path()
         ))
     }
 
-    /// Generate presigned URLs for downloading model version files.
+    /// Generate presigned URLs for downloading model version files. The version is
+    /// selected as in [`get_model_version`](Self::get_model_version).
     ///
     /// The client must be logged in before calling this method.
     pub fn presign_model_download(
@@ -43,10 +107,171 @@ impl Client {
         namespace: &str,
         project_name: &str,
         model_name: &str,
-        version: u32,
+        version: impl Into<ModelVersionSelector>,
     ) -> Result<ModelDownloadResponse, ClientError> {
         self.transport.get_json(format!(
-            "projects/{namespace}/{project_name}/models/{model_name}/versions/{version}/download"
+            "projects/{namespace}/{project_name}/models/{model_name}/{}/download",
+            version.into().path()
         ))
     }
+
+    /// Create a new model version produced by the given experiment.
+    ///
+    /// The response contains the new version number and presigned multipart upload URLs
+    /// for each file. Once every part has been uploaded, call
+    /// [`complete_model_version_upload`](Self::complete_model_version_upload).
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn create_model_version(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_name: &str,
+        exp_num: i32,
+        files: Vec<ModelFileSpecRequest>,
+    ) -> Result<ModelVersionCreationResponse, ClientError> {
+        self.transport.post_json(
+            format!("projects/{namespace}/{project_name}/models/{model_name}/versions"),
+            Some(CreateModelVersionRequest {
+                experiment_num: exp_num,
+                files,
+            }),
+        )
+    }
+
+    /// Complete a model version upload.
+    ///
+    /// The client must be logged in before calling this method.
+    ///
+    /// If `file_names` is None, all files in the version will be marked as complete.
+    /// If `file_names` is Some, only the specified files will be marked as complete.
+    pub fn complete_model_version_upload(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_name: &str,
+        version: u32,
+        file_names: Option<Vec<String>>,
+    ) -> Result<(), ClientError> {
+        self.transport.post(
+            format!(
+                "projects/{namespace}/{project_name}/models/{model_name}/versions/{version}/complete"
+            ),
+            Some(CompleteUploadRequest { file_names }),
+        )
+    }
+
+    /// Delete a model version.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn delete_model_version(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_name: &str,
+        version: u32,
+    ) -> Result<(), ClientError> {
+        self.transport.delete(format!(
+            "projects/{namespace}/{project_name}/models/{model_name}/versions/{version}"
+        ))
+    }
+
+    /// List the aliases of a model and the versions they point to.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_model_aliases(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_name: &str,
+    ) -> Result<ModelAliasListResponse, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{namespace}/{project_name}/models/{model_name}/aliases"
+        ))
+    }
+
+    /// Point an alias at a model version, moving it if it already exists.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn set_model_alias(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_name: &str,
+        alias: &ModelAlias,
+        version: u32,
+    ) -> Result<ModelAliasResponse, ClientError> {
+        self.transport.put_json(
+            format!("projects/{namespace}/{project_name}/models/{model_name}/aliases/{alias}"),
+            Some(SetModelAliasRequest { version }),
+        )
+    }
+
+    /// Remove an alias from a model. The version it pointed to is left untouched.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn delete_model_alias(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_name: &str,
+        alias: &ModelAlias,
+    ) -> Result<(), ClientError> {
+        self.transport.delete(format!(
+            "projects/{namespace}/{project_name}/models/{model_name}/aliases/{alias}"
+        ))
+    }
+
+    /// Get a model version by its id, as recorded in experiment inputs.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn get_model_version_by_id(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_version_id: &str,
+    ) -> Result<ModelVersionResponse, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{namespace}/{project_name}/model-versions/{model_version_id}"
+        ))
+    }
+
+    /// Walk back from a model version through the experiment that produced it to
+    /// the inputs it used, following inputs that are model versions recursively.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn model_lineage(
+        &self,
+        namespace: &str,
+        project_name: &str,
+        model_name: &str,
+        version: impl Into<ModelVersionSelector>,
+    ) -> Result<ModelLineage, ClientError> {
+        let start = self.get_model_version(namespace, project_name, model_name, version)?;
+
+        ModelLineage::walk(
+            start.into(),
+            |id| {
+                self.get_model_version_by_id(namespace, project_name, id)
+                    .map(Into::into)
+            },
+            |exp_num| {
+                Ok(self
+                    .get_experiment(namespace, project_name, exp_num)?
+                    .inputs)
+            },
+        )
+    }
+}
+
+impl From<ModelVersionResponse> for LineageVersion {
+    fn from(model_version: ModelVersionResponse) -> Self {
+        LineageVersion {
+            id: model_version.id,
+            version: model_version.version,
+            experiment_num: model_version
+                .experiment
+                .map(|experiment| experiment.experiment_num),
+        }
+    }
 }
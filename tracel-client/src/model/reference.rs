@@ -0,0 +1,185 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error;
+
+/// A named pointer to a model version. `production` and `staging` are the stage
+/// tags understood by the UI; any other valid name is a custom tag.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub enum ModelAlias {
+    Production,
+    Staging,
+    Custom(CustomModelAlias),
+}
+
+/// Name of a custom alias, validated by [`ModelAlias::new`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomModelAlias(String);
+
+impl CustomModelAlias {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ModelAlias {
+    /// Validates an alias name. `production` and `staging` give the stage tags,
+    /// so two aliases with the same name always compare equal.
+    pub fn new(name: &str) -> Result<Self, ModelRefParseError> {
+        name.parse()
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModelAlias::Production => "production",
+            ModelAlias::Staging => "staging",
+            ModelAlias::Custom(name) => name.as_str(),
+        }
+    }
+}
+
+impl Display for ModelAlias {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ModelAlias {
+    type Err = ModelRefParseError;
+
+    /// Alias names are made of ASCII alphanumerics, `-`, `_` and `.`, and cannot be
+    /// purely numeric so they are never mistaken for a version number.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ModelRefParseError::EmptyAlias);
+        }
+        if s.chars().all(|c| c.is_ascii_digit()) {
+            return Err(ModelRefParseError::NumericAlias(s.to_string()));
+        }
+        if !s
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(ModelRefParseError::InvalidAlias(s.to_string()));
+        }
+
+        Ok(match s {
+            "production" => ModelAlias::Production,
+            "staging" => ModelAlias::Staging,
+            _ => ModelAlias::Custom(CustomModelAlias(s.to_string())),
+        })
+    }
+}
+
+/// Selects a model version either by its number or through an alias.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModelVersionSelector {
+    Version(u32),
+    Alias(ModelAlias),
+}
+
+impl ModelVersionSelector {
+    /// Route segment identifying the selected version, relative to the model route.
+    pub(crate) fn path(&self) -> String {
+        match self {
+            ModelVersionSelector::Version(version) => format!("versions/{version}"),
+            ModelVersionSelector::Alias(alias) => format!("aliases/{alias}"),
+        }
+    }
+}
+
+impl From<u32> for ModelVersionSelector {
+    fn from(version: u32) -> Self {
+        ModelVersionSelector::Version(version)
+    }
+}
+
+impl From<ModelAlias> for ModelVersionSelector {
+    fn from(alias: ModelAlias) -> Self {
+        ModelVersionSelector::Alias(alias)
+    }
+}
+
+/// A reference to a model version, written `model@3` for a version number or
+/// `model:production` for an alias.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct ModelRef {
+    pub model: String,
+    pub selector: ModelVersionSelector,
+}
+
+impl ModelRef {
+    pub fn version(model: impl Into<String>, version: u32) -> Self {
+        Self {
+            model: model.into(),
+            selector: ModelVersionSelector::Version(version),
+        }
+    }
+
+    pub fn alias(model: impl Into<String>, alias: ModelAlias) -> Self {
+        Self {
+            model: model.into(),
+            selector: ModelVersionSelector::Alias(alias),
+        }
+    }
+}
+
+impl Display for ModelRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.selector {
+            ModelVersionSelector::Version(version) => write!(f, "{}@{version}", self.model),
+            ModelVersionSelector::Alias(alias) => write!(f, "{}:{alias}", self.model),
+        }
+    }
+}
+
+impl FromStr for ModelRef {
+    type Err = ModelRefParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (model, selector) = if let Some((model, version)) = s.rsplit_once('@') {
+            let version = version
+                .parse::<u32>()
+                .map_err(|_| ModelRefParseError::InvalidVersion(version.to_string()))?;
+            (model, ModelVersionSelector::Version(version))
+        } else if let Some((model, alias)) = s.rsplit_once(':') {
+            (model, ModelVersionSelector::Alias(alias.parse()?))
+        } else {
+            return Err(ModelRefParseError::MissingSelector(s.to_string()));
+        };
+
+        if model.is_empty() {
+            return Err(ModelRefParseError::EmptyModelName);
+        }
+
+        Ok(Self {
+            model: model.to_string(),
+            selector,
+        })
+    }
+}
+
+impl TryFrom<&str> for ModelRef {
+    type Error = ModelRefParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ModelRefParseError {
+    #[error("Model reference `{0}` must be written `model@version` or `model:alias`")]
+    MissingSelector(String),
+    #[error("Model name cannot be empty")]
+    EmptyModelName,
+    #[error("Invalid model version `{0}`")]
+    InvalidVersion(String),
+    #[error("Model alias cannot be empty")]
+    EmptyAlias,
+    #[error("Model alias `{0}` cannot be purely numeric")]
+    NumericAlias(String),
+    #[error("Model alias `{0}` may only contain ASCII alphanumerics, `-`, `_` and `.`")]
+    InvalidAlias(String),
+}
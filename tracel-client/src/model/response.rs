@@ -1,5 +1,8 @@
 use serde::Deserialize;
 
+use crate::artifact::response::MultipartUploadResponse;
+use crate::model::reference::ModelAlias;
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct CreatedByUserResponse {
     pub id: i32,
@@ -16,7 +19,70 @@ pub struct ModelVersionResponse {
     pub checksum: String,
     pub created_by: CreatedByUserResponse,
     pub created_at: String,
-    pub manifest: serde_json::Value,
+    pub manifest: ModelVersionManifestResponse,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct FileManifestResponse {
+    pub files: Vec<FileDescriptorResponse>,
+}
+
+impl FileManifestResponse {
+    /// Total size in bytes of the files listed in the manifest.
+    pub fn total_size_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.size_bytes).sum()
+    }
+
+    pub fn file(&self, rel_path: &str) -> Option<&FileDescriptorResponse> {
+        self.files.iter().find(|file| file.rel_path == rel_path)
+    }
+}
+
+pub type ModelVersionManifestResponse = FileManifestResponse;
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct FileDescriptorResponse {
+    pub rel_path: String,
+    pub size_bytes: u64,
+    pub checksum: String,
+    /// Present when the file is a Burn record.
+    #[serde(default)]
+    pub record: Option<BurnRecordMetadataResponse>,
+}
+
+/// How a Burn record file was saved.
+#[derive(Deserialize, Clone, Debug)]
+pub struct BurnRecordMetadataResponse {
+    pub format: RecordFormatResponse,
+    pub precision: RecordPrecisionResponse,
+    pub burn_version: Option<String>,
+}
+
+/// Burn recorder used to write a record file.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RecordFormatResponse {
+    NamedMpk,
+    NamedMpkGz,
+    Bin,
+    BinGz,
+    PrettyJson,
+    Burnpack,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Precision settings a Burn record was saved with.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RecordPrecisionResponse {
+    Half,
+    Full,
+    Double,
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -46,3 +112,39 @@ pub struct PresignedModelFileUrlResponse {
     pub rel_path: String,
     pub url: String,
 }
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ModelListResponse {
+    pub items: Vec<ModelResponse>,
+    pub total: usize,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ModelVersionListResponse {
+    pub items: Vec<ModelVersionResponse>,
+    pub total: usize,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PresignedModelFileUploadUrlsResponse {
+    pub rel_path: String,
+    pub urls: MultipartUploadResponse,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ModelVersionCreationResponse {
+    pub version: u32,
+    pub files: Vec<PresignedModelFileUploadUrlsResponse>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ModelAliasResponse {
+    pub alias: ModelAlias,
+    pub version: u32,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ModelAliasListResponse {
+    pub items: Vec<ModelAliasResponse>,
+}
@@ -0,0 +1,92 @@
+pub mod request;
+pub mod response;
+
+use crate::{
+    Client, ClientError,
+    organization::{
+        request::{InviteOrganizationMemberRequest, ListOrganizationMembersQuery},
+        response::{OrganizationInvitationResponse, OrganizationMemberListResponse},
+    },
+    project::{request::ListProjectsQuery, response::ProjectListResponse},
+};
+
+impl Client {
+    /// List the members of an organization and their roles.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_organization_members(
+        &self,
+        organization_name: &str,
+        query: ListOrganizationMembersQuery,
+    ) -> Result<OrganizationMemberListResponse, ClientError> {
+        let mut url = self
+            .transport
+            .join(&format!("organizations/{organization_name}/members"));
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(page) = query.page {
+                pairs.append_pair("page", &page.to_string());
+            }
+            if let Some(per_page) = query.per_page {
+                pairs.append_pair("per_page", &per_page.to_string());
+            }
+        }
+
+        self.transport.get_json(url)
+    }
+
+    /// Invite someone to an organization by email. They become a member with the
+    /// requested role once they accept the invitation.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn invite_organization_member(
+        &self,
+        organization_name: &str,
+        request: InviteOrganizationMemberRequest,
+    ) -> Result<OrganizationInvitationResponse, ClientError> {
+        self.transport.post_json(
+            format!("organizations/{organization_name}/invitations"),
+            Some(request),
+        )
+    }
+
+    /// Remove a member from an organization.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn remove_organization_member(
+        &self,
+        organization_name: &str,
+        username: &str,
+    ) -> Result<(), ClientError> {
+        self.transport.delete(format!(
+            "organizations/{organization_name}/members/{username}"
+        ))
+    }
+
+    /// List the projects of an organization.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_organization_projects(
+        &self,
+        organization_name: &str,
+        query: ListProjectsQuery,
+    ) -> Result<ProjectListResponse, ClientError> {
+        let mut url = self
+            .transport
+            .join(&format!("organizations/{organization_name}/projects"));
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(page) = query.page {
+                pairs.append_pair("page", &page.to_string());
+            }
+            if let Some(per_page) = query.per_page {
+                pairs.append_pair("per_page", &per_page.to_string());
+            }
+            if query.include_archived {
+                pairs.append_pair("include_archived", "true");
+            }
+        }
+
+        self.transport.get_json(url)
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+/// Role to give a member within an organization.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OrganizationRoleRequest {
+    Owner,
+    Admin,
+    Member,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct InviteOrganizationMemberRequest {
+    pub email: String,
+    pub role: OrganizationRoleRequest,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ListOrganizationMembersQuery {
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OrganizationRoleResponse {
+    Owner,
+    Admin,
+    Member,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct OrganizationMemberResponse {
+    pub username: String,
+    pub namespace: String,
+    pub role: OrganizationRoleResponse,
+    pub joined_at: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct OrganizationMemberListResponse {
+    pub items: Vec<OrganizationMemberResponse>,
+    pub total: usize,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct OrganizationInvitationResponse {
+    pub id: String,
+    pub email: String,
+    pub role: OrganizationRoleResponse,
+    pub expires_at: String,
+}
@@ -1,11 +1,22 @@
+pub mod publish;
 pub mod request;
 pub mod response;
 
+use sha2::{Digest, Sha256};
+
 use crate::{
     Client, ClientError,
+    error::ApiErrorCode,
     project::{
-        request::{CreateProjectRequest, PublishProjectVersionRequest},
-        response::{CodeUploadUrlsResponse, ProjectResponse},
+        request::{
+            Arch, CreateProjectRequest, ListCodeVersionsQuery, ListProjectsQuery, Os,
+            PublishProjectVersionRequest, UpdateProjectRequest, canonical_target_triple,
+        },
+        response::{
+            CodeArtifactResponse, CodeDownloadResponse, CodeUploadUrlsResponse,
+            CodeVersionListResponse, CodeVersionResponse, ProjectListResponse,
+            ProjectMemberListResponse, ProjectResponse,
+        },
     },
 };
 
@@ -54,6 +65,129 @@ impl Client {
         )
     }
 
+    /// Get a project, creating it if it does not exist yet. Projects owned by the
+    /// logged-in user are created as user projects, others as organization projects.
+    /// `project_description` is only used when the project is created.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn ensure_project(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        project_description: Option<&str>,
+    ) -> Result<ProjectResponse, ClientError> {
+        match self.get_project(owner_name, project_name) {
+            Err(e) if e.is_not_found() => {}
+            result => return result,
+        }
+
+        let user = self.get_current_user()?;
+        let created = if user.namespace == owner_name {
+            self.create_user_project(project_name, project_description)
+        } else {
+            self.create_organization_project(owner_name, project_name, project_description)
+        };
+
+        // Another client may have created the project since it was looked up.
+        match created {
+            Err(e) if matches!(e.code(), Some(ApiErrorCode::ProjectAlreadyExists)) => {
+                self.get_project(owner_name, project_name)
+            }
+            result => result,
+        }
+    }
+
+    /// List the projects of the logged-in user.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_user_projects(
+        &self,
+        query: ListProjectsQuery,
+    ) -> Result<ProjectListResponse, ClientError> {
+        let mut url = self.transport.join("user/projects");
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(page) = query.page {
+                pairs.append_pair("page", &page.to_string());
+            }
+            if let Some(per_page) = query.per_page {
+                pairs.append_pair("per_page", &per_page.to_string());
+            }
+            if query.include_archived {
+                pairs.append_pair("include_archived", "true");
+            }
+        }
+
+        self.transport.get_json(url)
+    }
+
+    /// Replace the description of a project. `None` clears it.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn update_project_description(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        project_description: Option<&str>,
+    ) -> Result<ProjectResponse, ClientError> {
+        self.transport.patch_json(
+            format!("projects/{owner_name}/{project_name}"),
+            Some(UpdateProjectRequest {
+                description: project_description.map(|desc| desc.to_string()),
+            }),
+        )
+    }
+
+    /// Archive a project. Archived projects are read-only and hidden from listings
+    /// unless requested.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn archive_project(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+    ) -> Result<ProjectResponse, ClientError> {
+        self.transport.post_json(
+            format!("projects/{owner_name}/{project_name}/archive"),
+            None::<()>,
+        )
+    }
+
+    /// Restore an archived project.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn unarchive_project(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+    ) -> Result<ProjectResponse, ClientError> {
+        self.transport.post_json(
+            format!("projects/{owner_name}/{project_name}/unarchive"),
+            None::<()>,
+        )
+    }
+
+    /// Permanently delete a project along with its experiments, models and code
+    /// versions.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn delete_project(&self, owner_name: &str, project_name: &str) -> Result<(), ClientError> {
+        self.transport
+            .delete(format!("projects/{owner_name}/{project_name}"))
+    }
+
+    /// List the members of a project and their roles.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_project_members(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+    ) -> Result<ProjectMemberListResponse, ClientError> {
+        self.transport
+            .get_json(format!("projects/{owner_name}/{project_name}/members"))
+    }
+
     /// Request presigned upload URLs for a new code version. The returned
     /// `urls` map is keyed by the binary target-triple string (e.g.
     /// `x86_64-unknown-linux-gnu`) for binaries, or `source.zip` for source.
@@ -86,4 +220,128 @@ impl Client {
     pub fn upload_bytes_to_url(&self, url: &str, bytes: Vec<u8>) -> Result<(), ClientError> {
         self.transport.upload_bytes_to_url(url, bytes)
     }
+
+    /// List the published code versions of a project.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn list_code_versions(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        query: ListCodeVersionsQuery,
+    ) -> Result<CodeVersionListResponse, ClientError> {
+        let mut url = self
+            .transport
+            .join(&format!("projects/{owner_name}/{project_name}/code"));
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(page) = query.page {
+                pairs.append_pair("page", &page.to_string());
+            }
+            if let Some(per_page) = query.per_page {
+                pairs.append_pair("per_page", &per_page.to_string());
+            }
+        }
+
+        self.transport.get_json(url)
+    }
+
+    /// Get a code version by id or by digest.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn get_code_version(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        id_or_digest: &str,
+    ) -> Result<CodeVersionResponse, ClientError> {
+        self.transport.get_json(format!(
+            "projects/{owner_name}/{project_name}/code/{id_or_digest}"
+        ))
+    }
+
+    /// Request a presigned download URL for the content of a code version: the
+    /// binary for `target_triple` of a binaries version, or the source archive when
+    /// `target_triple` is `None`.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn presign_code_version_download(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        code_version_id: &str,
+        target_triple: Option<&str>,
+    ) -> Result<CodeDownloadResponse, ClientError> {
+        let mut url = self.transport.join(&format!(
+            "projects/{owner_name}/{project_name}/code/{code_version_id}/download"
+        ));
+        if let Some(target_triple) = target_triple {
+            let os = target_triple.parse::<Os>()?;
+            let architecture = target_triple.parse::<Arch>()?;
+            url.query_pairs_mut()
+                .append_pair("target", canonical_target_triple(os, architecture));
+        }
+
+        self.transport.get_json(url)
+    }
+
+    /// Download the content of a code version, checking it against the checksum
+    /// recorded when it was published. See [`Client::presign_code_version_download`]
+    /// for what `target_triple` selects.
+    ///
+    /// Fails with [`ClientError::NotFound`] when the version has no binary for
+    /// `target_triple`, and with [`ClientError::ChecksumMismatch`] when the
+    /// downloaded bytes do not match the recorded checksum.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn download_code_version(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        code_version_id: &str,
+        target_triple: Option<&str>,
+    ) -> Result<Vec<u8>, ClientError> {
+        let version = self.get_code_version(owner_name, project_name, code_version_id)?;
+        let expected_checksum = match (&version.artifact, target_triple) {
+            (CodeArtifactResponse::Source { source }, None) => &source.checksum,
+            (CodeArtifactResponse::Binaries { .. }, Some(target_triple)) => {
+                let os = target_triple.parse::<Os>()?;
+                let architecture = target_triple.parse::<Arch>()?;
+                &version
+                    .binary(os, architecture)
+                    .ok_or(ClientError::NotFound)?
+                    .checksum
+            }
+            (CodeArtifactResponse::Source { .. }, Some(_)) => {
+                return Err(ClientError::InvalidRequest(format!(
+                    "Code version {code_version_id} is a source archive and has no target"
+                )));
+            }
+            (CodeArtifactResponse::Binaries { .. }, None) => {
+                return Err(ClientError::InvalidRequest(format!(
+                    "Code version {code_version_id} holds binaries, a target triple is required"
+                )));
+            }
+        };
+
+        let download = self.presign_code_version_download(
+            owner_name,
+            project_name,
+            &version.id,
+            target_triple,
+        )?;
+        let bytes = self
+            .transport
+            .download_bytes_from_url(&download.url, None)?;
+
+        let checksum = format!("{:x}", Sha256::digest(&bytes));
+        if &checksum != expected_checksum {
+            return Err(ClientError::ChecksumMismatch {
+                expected: expected_checksum.clone(),
+                actual: checksum,
+            });
+        }
+
+        Ok(bytes)
+    }
 }
@@ -0,0 +1,334 @@
+use std::collections::BTreeMap;
+use std::fs;
+#[cfg(feature = "source-archive")]
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "source-archive")]
+use ignore::WalkBuilder;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+#[cfg(feature = "source-archive")]
+use zip::{CompressionMethod, DateTime, ZipWriter, write::SimpleFileOptions};
+
+#[cfg(feature = "source-archive")]
+use crate::project::request::PublishSourceRequest;
+use crate::{
+    Client, ClientError,
+    project::request::{
+        Arch, Os, PublishArtifactRequest, PublishBinaryRequest, PublishProjectVersionRequest,
+        TargetParseError, canonical_target_triple,
+    },
+};
+
+/// Key of the source archive in [`CodeUploadUrlsResponse::urls`](super::response::CodeUploadUrlsResponse).
+#[cfg(feature = "source-archive")]
+pub const SOURCE_ARCHIVE_NAME: &str = "source.zip";
+
+#[derive(Error, Debug)]
+pub enum PublishCodeError {
+    #[error("{0} does not contain a Cargo.toml")]
+    NotACargoProject(PathBuf),
+    #[error("{0} is a member of the Cargo workspace in {1}, publish the workspace root instead")]
+    WorkspaceMember(PathBuf, PathBuf),
+    #[cfg(feature = "source-archive")]
+    #[error("Failed to list project files: {0}")]
+    Walk(#[from] ignore::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "source-archive")]
+    #[error("Failed to build the source archive: {0}")]
+    Zip(#[from] zip::result::ZipError),
+    #[error(transparent)]
+    UnsupportedTarget(#[from] TargetParseError),
+    #[error("Targets `{0}` and `{1}` are both built for {2}")]
+    DuplicateTarget(String, String, &'static str),
+    #[error("The server returned no upload URL for `{0}`")]
+    MissingUploadUrl(String),
+    #[error(transparent)]
+    Client(#[from] ClientError),
+}
+
+/// A zipped snapshot of a project's sources.
+///
+/// Only `.gitignore` files inside the project apply; global git excludes,
+/// `.git/info/exclude`, `.ignore` files and ignore files above the project are
+/// not read, so the archive does not depend on the machine building it. The
+/// `.git` directory and the root `target` directory are left out, and so is any
+/// subdirectory that is a Cargo workspace of its own. Entries are sorted and
+/// carry fixed timestamps and permissions, so the same sources always produce
+/// the same archive.
+///
+/// The project must be a standalone package or a workspace root: a workspace
+/// member would be archived without the workspace manifest and lock file, so it
+/// is rejected with [`PublishCodeError::WorkspaceMember`].
+#[cfg(feature = "source-archive")]
+#[derive(Debug, Clone)]
+pub struct SourceArchive {
+    pub bytes: Vec<u8>,
+    /// Paths of the archived files, relative to the project root and `/`-separated.
+    pub files: Vec<String>,
+    /// SHA-256 of the archive bytes, hex encoded.
+    pub checksum: String,
+    /// SHA-256 over the paths and contents of the archived files, hex encoded.
+    /// Unlike `checksum`, it does not depend on how the archive is encoded.
+    pub digest: String,
+}
+
+#[cfg(feature = "source-archive")]
+impl SourceArchive {
+    pub fn build(project_dir: impl AsRef<Path>) -> Result<Self, PublishCodeError> {
+        let root = project_dir.as_ref();
+        if !root.join("Cargo.toml").is_file() {
+            return Err(PublishCodeError::NotACargoProject(root.to_path_buf()));
+        }
+        if !is_workspace_root(root) {
+            let absolute = root.canonicalize()?;
+            if let Some(workspace) = absolute
+                .ancestors()
+                .skip(1)
+                .find(|dir| is_workspace_root(dir))
+            {
+                return Err(PublishCodeError::WorkspaceMember(
+                    root.to_path_buf(),
+                    workspace.to_path_buf(),
+                ));
+            }
+        }
+
+        let walk_root = root.to_path_buf();
+        let walker = WalkBuilder::new(root)
+            .hidden(false)
+            .require_git(false)
+            .git_global(false)
+            .git_exclude(false)
+            .ignore(false)
+            .parents(false)
+            .filter_entry(move |entry| {
+                let path = entry.path();
+                if path == walk_root || !entry.file_type().is_some_and(|kind| kind.is_dir()) {
+                    return true;
+                }
+                let is_git = entry.file_name() == ".git";
+                let is_root_target = path == walk_root.join("target");
+                !is_git && !is_root_target && !is_workspace_root(path)
+            })
+            .build();
+
+        let mut files = Vec::new();
+        for entry in walker {
+            let entry = entry?;
+            if !entry.file_type().is_some_and(|kind| kind.is_file()) {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(root)
+                .expect("Walked entries should be under the project root");
+            let name = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.push((name, entry.into_path()));
+        }
+        files.sort();
+
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .last_modified_time(DateTime::default())
+            .unix_permissions(0o644);
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let mut digest = Sha256::new();
+        for (name, path) in &files {
+            let content = fs::read(path)?;
+            digest.update(name.as_bytes());
+            digest.update([0]);
+            digest.update(Sha256::digest(&content));
+
+            writer.start_file(name.as_str(), options)?;
+            writer.write_all(&content)?;
+        }
+        let bytes = writer.finish()?.into_inner();
+
+        Ok(SourceArchive {
+            checksum: format!("{:x}", Sha256::digest(&bytes)),
+            digest: format!("{:x}", digest.finalize()),
+            files: files.into_iter().map(|(name, _)| name).collect(),
+            bytes,
+        })
+    }
+}
+
+/// Whether a directory holds a Cargo workspace manifest.
+#[cfg(feature = "source-archive")]
+fn is_workspace_root(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml")).is_ok_and(|manifest| {
+        manifest
+            .lines()
+            .any(|line| line.trim_start().starts_with("[workspace"))
+    })
+}
+
+/// A code version after publishing.
+#[derive(Debug, Clone)]
+pub struct PublishedCodeVersion {
+    pub id: String,
+    pub digest: String,
+    /// `false` when a version with the same digest already existed and nothing
+    /// was uploaded.
+    pub uploaded: bool,
+}
+
+impl Client {
+    /// Package the sources of the project in `project_dir` and publish them as a
+    /// code version. See [`SourceArchive`] for which files are included.
+    ///
+    /// The client must be logged in before calling this method.
+    #[cfg(feature = "source-archive")]
+    pub fn publish_source(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        project_dir: impl AsRef<Path>,
+    ) -> Result<PublishedCodeVersion, PublishCodeError> {
+        let archive = SourceArchive::build(project_dir)?;
+
+        let version = self.publish_project_version_urls(
+            owner_name,
+            project_name,
+            PublishProjectVersionRequest {
+                digest: archive.digest.clone(),
+                artifact: PublishArtifactRequest::Source {
+                    source: PublishSourceRequest {
+                        checksum: archive.checksum.clone(),
+                        size: archive.bytes.len() as u64,
+                    },
+                },
+            },
+        )?;
+
+        let Some(urls) = version.urls else {
+            return Ok(PublishedCodeVersion {
+                id: version.id,
+                digest: version.digest,
+                uploaded: false,
+            });
+        };
+
+        let url = urls
+            .get(SOURCE_ARCHIVE_NAME)
+            .ok_or_else(|| PublishCodeError::MissingUploadUrl(SOURCE_ARCHIVE_NAME.to_string()))?;
+        self.upload_bytes_to_url(url, archive.bytes)?;
+        self.complete_project_version_upload(owner_name, project_name, &version.id)?;
+
+        Ok(PublishedCodeVersion {
+            id: version.id,
+            digest: version.digest,
+            uploaded: true,
+        })
+    }
+
+    /// Publish compiled binaries as a code version, one per Rust target triple,
+    /// e.g. `x86_64-unknown-linux-gnu` or `aarch64-apple-darwin`.
+    ///
+    /// Only one binary per operating system and architecture can be published. The
+    /// digest covers the targets and the binary checksums, so republishing the same
+    /// binaries uploads nothing.
+    ///
+    /// The client must be logged in before calling this method.
+    pub fn publish_binaries(
+        &self,
+        owner_name: &str,
+        project_name: &str,
+        paths_by_triple: impl IntoIterator<Item = (impl Into<String>, impl AsRef<Path>)>,
+    ) -> Result<PublishedCodeVersion, PublishCodeError> {
+        struct Binary {
+            os: Os,
+            architecture: Arch,
+            checksum: String,
+            bytes: Vec<u8>,
+        }
+
+        let mut binaries: BTreeMap<String, Binary> = BTreeMap::new();
+        for (triple, path) in paths_by_triple {
+            let triple = triple.into();
+            let os = triple.parse::<Os>()?;
+            let architecture = triple.parse::<Arch>()?;
+
+            let canonical = canonical_target_triple(os, architecture);
+            let existing = binaries
+                .iter()
+                .find(|(_, binary)| (binary.os, binary.architecture) == (os, architecture));
+            if let Some((existing, _)) = existing {
+                return Err(PublishCodeError::DuplicateTarget(
+                    existing.clone(),
+                    triple,
+                    canonical,
+                ));
+            }
+
+            let bytes = fs::read(path)?;
+            binaries.insert(
+                triple,
+                Binary {
+                    os,
+                    architecture,
+                    checksum: format!("{:x}", Sha256::digest(&bytes)),
+                    bytes,
+                },
+            );
+        }
+
+        let mut digest = Sha256::new();
+        for binary in binaries.values() {
+            digest.update(canonical_target_triple(binary.os, binary.architecture));
+            digest.update([0]);
+            digest.update(&binary.checksum);
+            digest.update([0]);
+        }
+
+        let version = self.publish_project_version_urls(
+            owner_name,
+            project_name,
+            PublishProjectVersionRequest {
+                digest: format!("{:x}", digest.finalize()),
+                artifact: PublishArtifactRequest::Binaries {
+                    binaries: binaries
+                        .values()
+                        .map(|binary| PublishBinaryRequest {
+                            os: binary.os,
+                            architecture: binary.architecture,
+                            checksum: binary.checksum.clone(),
+                            size: binary.bytes.len() as u64,
+                        })
+                        .collect(),
+                },
+            },
+        )?;
+
+        let Some(urls) = version.urls else {
+            return Ok(PublishedCodeVersion {
+                id: version.id,
+                digest: version.digest,
+                uploaded: false,
+            });
+        };
+
+        for (triple, binary) in binaries {
+            let canonical = canonical_target_triple(binary.os, binary.architecture);
+            let url = urls
+                .get(&triple)
+                .or_else(|| urls.get(canonical))
+                .ok_or(PublishCodeError::MissingUploadUrl(triple))?;
+            self.upload_bytes_to_url(url, binary.bytes)?;
+        }
+        self.complete_project_version_upload(owner_name, project_name, &version.id)?;
+
+        Ok(PublishedCodeVersion {
+            id: version.id,
+            digest: version.digest,
+            uploaded: true,
+        })
+    }
+}
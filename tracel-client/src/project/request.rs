@@ -1,4 +1,9 @@
-use serde::Serialize;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::ClientError;
 
 #[derive(Serialize, Clone, Debug)]
 pub(crate) struct CreateProjectRequest {
@@ -6,9 +11,21 @@ pub(crate) struct CreateProjectRequest {
     pub description: Option<String>,
 }
 
+#[derive(Serialize, Clone, Debug)]
+pub(crate) struct UpdateProjectRequest {
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ListProjectsQuery {
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+    pub include_archived: bool,
+}
+
 /// Operating system of a binary target. Serializes to match the server's
 /// `OperatingSystem` enum (lowercase: `windows`, `linux`, `macos`).
-#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum Os {
     Windows,
@@ -16,15 +33,100 @@ pub enum Os {
     Macos,
 }
 
+impl FromStr for Os {
+    type Err = TargetParseError;
+
+    /// Parses the operating system of a Rust target triple such as
+    /// `x86_64-unknown-linux-gnu`, or a bare name such as `linux`.
+    ///
+    /// Triples must have the `<arch>-<vendor>-<os>[-<env>]` shape and target a
+    /// desktop environment: Linux with `gnu` or `musl`, Windows with `msvc`, `gnu`
+    /// or `gnullvm`, or Darwin. Android, `gnux32` and other environments are
+    /// rejected.
+    fn from_str(target: &str) -> Result<Self, Self::Err> {
+        let components = target.split('-').collect::<Vec<_>>();
+        let os = match components.as_slice() {
+            ["linux"] => Some(Os::Linux),
+            ["windows"] => Some(Os::Windows),
+            ["darwin" | "macos"] => Some(Os::Macos),
+            [_, _, "linux", "gnu" | "musl"] => Some(Os::Linux),
+            [_, "pc", "windows", "msvc" | "gnu" | "gnullvm"] => Some(Os::Windows),
+            [_, "apple", "darwin"] => Some(Os::Macos),
+            _ => None,
+        };
+
+        os.ok_or_else(|| TargetParseError::UnsupportedOs(target.to_string()))
+    }
+}
+
+impl TryFrom<&str> for Os {
+    type Error = TargetParseError;
+
+    fn try_from(target: &str) -> Result<Self, Self::Error> {
+        target.parse()
+    }
+}
+
 /// CPU architecture of a binary target. Serializes to match the server's
 /// `Architecture` enum (lowercase: `x86_64`, `arm64`).
-#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum Arch {
     X86_64,
     Arm64,
 }
 
+impl FromStr for Arch {
+    type Err = TargetParseError;
+
+    /// Parses the architecture of a Rust target triple such as
+    /// `aarch64-apple-darwin`, or a bare name such as `x86_64`.
+    fn from_str(target: &str) -> Result<Self, Self::Err> {
+        match target.split('-').next() {
+            Some("x86_64" | "amd64") => Ok(Arch::X86_64),
+            Some("aarch64" | "arm64") => Ok(Arch::Arm64),
+            _ => Err(TargetParseError::UnsupportedArch(target.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Arch {
+    type Error = TargetParseError;
+
+    fn try_from(target: &str) -> Result<Self, Self::Error> {
+        target.parse()
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TargetParseError {
+    #[error(
+        "Unsupported operating system in target `{0}`, expected a Linux (gnu or musl), Windows or macOS target"
+    )]
+    UnsupportedOs(String),
+    #[error("Unsupported architecture in target `{0}`, expected x86_64 or aarch64")]
+    UnsupportedArch(String),
+}
+
+impl From<TargetParseError> for ClientError {
+    fn from(error: TargetParseError) -> Self {
+        ClientError::InvalidRequest(error.to_string())
+    }
+}
+
+/// Target triple the server uses as the upload URL key of a binary built for
+/// `os` and `architecture`.
+pub fn canonical_target_triple(os: Os, architecture: Arch) -> &'static str {
+    match (os, architecture) {
+        (Os::Linux, Arch::X86_64) => "x86_64-unknown-linux-gnu",
+        (Os::Linux, Arch::Arm64) => "aarch64-unknown-linux-gnu",
+        (Os::Windows, Arch::X86_64) => "x86_64-pc-windows-msvc",
+        (Os::Windows, Arch::Arm64) => "aarch64-pc-windows-msvc",
+        (Os::Macos, Arch::X86_64) => "x86_64-apple-darwin",
+        (Os::Macos, Arch::Arm64) => "aarch64-apple-darwin",
+    }
+}
+
 /// A single compiled binary in a `binaries` code version.
 #[derive(Debug, Serialize, Clone)]
 pub struct PublishBinaryRequest {
@@ -57,3 +159,9 @@ pub struct PublishProjectVersionRequest {
     pub digest: String,
     pub artifact: PublishArtifactRequest,
 }
+
+#[derive(Debug, Clone, Default)]
+pub struct ListCodeVersionsQuery {
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
+use crate::project::request::{Arch, Os};
+
 #[derive(Deserialize, Debug)]
 pub struct ProjectResponse {
     pub project_name: String,
@@ -9,6 +11,39 @@ pub struct ProjectResponse {
     pub namespace_type: String,
     pub description: String,
     pub created_by: String,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ProjectListResponse {
+    pub items: Vec<ProjectResponse>,
+    pub total: usize,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProjectRoleResponse {
+    Owner,
+    Admin,
+    Member,
+    Viewer,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ProjectMemberResponse {
+    pub username: String,
+    pub namespace: String,
+    pub role: ProjectRoleResponse,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ProjectMemberListResponse {
+    pub items: Vec<ProjectMemberResponse>,
+    pub total: usize,
 }
 
 #[derive(Debug, Deserialize)]
@@ -17,3 +52,57 @@ pub struct CodeUploadUrlsResponse {
     pub digest: String,
     pub urls: Option<HashMap<String, String>>,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct CodeVersionResponse {
+    pub id: String,
+    pub digest: String,
+    pub created_by: String,
+    pub created_at: String,
+    pub artifact: CodeArtifactResponse,
+}
+
+impl CodeVersionResponse {
+    /// The binary built for `os` and `architecture`, if this is a binaries version
+    /// that has one.
+    pub fn binary(&self, os: Os, architecture: Arch) -> Option<&CodeBinaryResponse> {
+        match &self.artifact {
+            CodeArtifactResponse::Binaries { binaries } => binaries
+                .iter()
+                .find(|binary| binary.os == os && binary.architecture == architecture),
+            CodeArtifactResponse::Source { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CodeArtifactResponse {
+    Binaries { binaries: Vec<CodeBinaryResponse> },
+    Source { source: CodeSourceResponse },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CodeBinaryResponse {
+    pub os: Os,
+    pub architecture: Arch,
+    pub checksum: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CodeSourceResponse {
+    pub checksum: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CodeVersionListResponse {
+    pub items: Vec<CodeVersionResponse>,
+    pub total: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CodeDownloadResponse {
+    pub url: String,
+}
@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Unit of a cleanup interval. Serializes to match the server's interval unit
+/// (snake_case: `seconds`, `minutes`, `hours`, `days`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupIntervalUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+}
+
+impl CleanupIntervalUnit {
+    /// Number of seconds in one unit.
+    pub fn as_secs(&self) -> u64 {
+        match self {
+            CleanupIntervalUnit::Seconds => 1,
+            CleanupIntervalUnit::Minutes => 60,
+            CleanupIntervalUnit::Hours => 60 * 60,
+            CleanupIntervalUnit::Days => 24 * 60 * 60,
+        }
+    }
+}
+
+/// How often the Station runs cleanup on an annotation set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CleanupInterval {
+    pub unit: CleanupIntervalUnit,
+    pub amount: u32,
+}
+
+impl CleanupInterval {
+    pub fn new(unit: CleanupIntervalUnit, amount: u32) -> Result<Self, CleanupPolicyError> {
+        let interval = Self { unit, amount };
+        interval.validate()?;
+        Ok(interval)
+    }
+
+    /// Checks that the interval is non-zero.
+    pub fn validate(&self) -> Result<(), CleanupPolicyError> {
+        if self.amount == 0 {
+            return Err(CleanupPolicyError::ZeroInterval);
+        }
+        Ok(())
+    }
+}
+
+/// Converts a duration to the coarsest unit that represents it exactly, e.g.
+/// 2 hours becomes `{ unit: Hours, amount: 2 }` and 90 minutes becomes
+/// `{ unit: Minutes, amount: 90 }`.
+impl TryFrom<Duration> for CleanupInterval {
+    type Error = CleanupPolicyError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if duration.subsec_nanos() != 0 {
+            return Err(CleanupPolicyError::SubSecondInterval);
+        }
+
+        let secs = duration.as_secs();
+        if secs == 0 {
+            return Err(CleanupPolicyError::ZeroInterval);
+        }
+
+        let unit = [
+            CleanupIntervalUnit::Days,
+            CleanupIntervalUnit::Hours,
+            CleanupIntervalUnit::Minutes,
+            CleanupIntervalUnit::Seconds,
+        ]
+        .into_iter()
+        .find(|unit| secs.is_multiple_of(unit.as_secs()))
+        .unwrap_or(CleanupIntervalUnit::Seconds);
+
+        let amount = u32::try_from(secs / unit.as_secs())
+            .map_err(|_| CleanupPolicyError::IntervalOverflow(duration))?;
+
+        Ok(Self { unit, amount })
+    }
+}
+
+impl From<CleanupInterval> for Duration {
+    fn from(interval: CleanupInterval) -> Self {
+        Duration::from_secs(interval.amount as u64 * interval.unit.as_secs())
+    }
+}
+
+/// Cleanup policy of an annotation set, used both when configuring a set and
+/// when reading it back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CleanupPolicy {
+    pub target_items: u32,
+    pub time_delta: CleanupInterval,
+}
+
+impl CleanupPolicy {
+    /// Creates a policy keeping `target_items` items, cleaned up every `interval`.
+    pub fn new(target_items: u32, interval: Duration) -> Result<Self, CleanupPolicyError> {
+        Ok(Self {
+            target_items,
+            time_delta: CleanupInterval::try_from(interval)?,
+        })
+    }
+
+    /// The cleanup interval as a [`Duration`].
+    pub fn interval(&self) -> Duration {
+        self.time_delta.into()
+    }
+
+    pub fn validate(&self) -> Result<(), CleanupPolicyError> {
+        self.time_delta.validate()
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CleanupPolicyError {
+    #[error("Cleanup interval must be greater than zero")]
+    ZeroInterval,
+    #[error("Cleanup interval must be a whole number of seconds")]
+    SubSecondInterval,
+    #[error("Cleanup interval {0:?} is too large to be represented")]
+    IntervalOverflow(Duration),
+}
@@ -1,23 +1,44 @@
+pub mod cleanup;
 pub mod request;
 pub mod response;
 
+pub use cleanup::{CleanupInterval, CleanupIntervalUnit, CleanupPolicy, CleanupPolicyError};
+#[allow(deprecated)]
 pub use request::{
     AddAnnotationSetItemRequest, AddAnnotationSetItemsRequest,
     AnnotationSetItemValidationFilterRequest, AnnotationSetItemsFilterRequest,
     CleanupIntervalRequest, CleanupIntervalUnitRequest, CleanupPolicyRequest,
-    CreateAnnotationSetRequest, PromoteAnnotationSetRequest, QueryAnnotationSetItemsRequest,
+    CreateAnnotationSetRequest, ListAnnotationSetsQuery, PromoteAnnotationSetRequest,
+    QueryAnnotationSetItemsRequest, RenameAnnotationSetRequest,
     UpdateAnnotationSetCleanupPolicyRequest, ValidateAnnotationSetItemRequest,
 };
+#[allow(deprecated)]
 pub use response::{
-    AnnotationSetItemListResponse, AnnotationSetItemResponse, AnnotationSetResponse,
-    CleanupIntervalResponse, CleanupIntervalUnitResponse, CleanupPolicyResponse,
-    PromotedDatasetVersionResponse,
+    AnnotationSetItemListResponse, AnnotationSetItemResponse, AnnotationSetListResponse,
+    AnnotationSetResponse, AnnotationSetSummaryResponse, CleanupIntervalResponse,
+    CleanupIntervalUnitResponse, CleanupPolicyResponse, PromotedDatasetVersionResponse,
 };
 
 use uuid::Uuid;
 
 use crate::{ClientError, transport::ApiTransport};
 
+const STATS_PAGE_SIZE: u32 = 500;
+
+/// Item and byte totals of an annotation set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnnotationSetStats {
+    pub item_count: u64,
+    pub validated_item_count: u64,
+    pub total_bytes: u64,
+}
+
+impl From<CleanupPolicyError> for ClientError {
+    fn from(error: CleanupPolicyError) -> Self {
+        ClientError::InvalidRequest(error.to_string())
+    }
+}
+
 pub struct AnnotationClient<'a> {
     transport: &'a ApiTransport,
 }
@@ -31,19 +52,96 @@ impl<'a> AnnotationClient<'a> {
         &self,
         request: CreateAnnotationSetRequest,
     ) -> Result<AnnotationSetResponse, ClientError> {
+        if let Some(policy) = &request.cleanup_policy {
+            policy.validate()?;
+        }
+
         self.transport.post_json("annotation-sets", Some(request))
     }
 
+    pub fn list_sets(
+        &self,
+        query: ListAnnotationSetsQuery,
+    ) -> Result<AnnotationSetListResponse, ClientError> {
+        let mut url = self.transport.join("annotation-sets");
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(page) = query.page {
+                pairs.append_pair("page", &page.to_string());
+            }
+            if let Some(per_page) = query.per_page {
+                pairs.append_pair("per_page", &per_page.to_string());
+            }
+        }
+
+        self.transport.get_json(url)
+    }
+
     pub fn get_set(&self, annotation_set_name: &str) -> Result<AnnotationSetResponse, ClientError> {
         self.transport
             .get_json(format!("annotation-sets/{annotation_set_name}"))
     }
 
+    /// Renames a set. Its items, validations and cleanup policy are kept.
+    pub fn rename_set(
+        &self,
+        annotation_set_name: &str,
+        request: RenameAnnotationSetRequest,
+    ) -> Result<AnnotationSetResponse, ClientError> {
+        self.transport.patch_json(
+            format!("annotation-sets/{annotation_set_name}"),
+            Some(request),
+        )
+    }
+
+    pub fn delete_set(&self, annotation_set_name: &str) -> Result<(), ClientError> {
+        self.transport
+            .delete(format!("annotation-sets/{annotation_set_name}"))
+    }
+
+    /// Computes item and byte totals by paging through every item of the set
+    /// without their payloads.
+    pub fn stats(&self, annotation_set_name: &str) -> Result<AnnotationSetStats, ClientError> {
+        let mut stats = AnnotationSetStats::default();
+        let mut page = 1;
+
+        loop {
+            let response = self.query_items(
+                annotation_set_name,
+                QueryAnnotationSetItemsRequest {
+                    page: Some(page),
+                    per_page: Some(STATS_PAGE_SIZE),
+                    include_data: false,
+                    filter: None,
+                },
+            )?;
+
+            for item in &response.items {
+                stats.item_count += 1;
+                stats.total_bytes += item.example_size_bytes;
+                if item.validated {
+                    stats.validated_item_count += 1;
+                }
+            }
+
+            if response.items.is_empty() || stats.item_count >= response.total_count {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(stats)
+    }
+
     pub fn update_cleanup_policy(
         &self,
         annotation_set_name: &str,
         request: UpdateAnnotationSetCleanupPolicyRequest,
     ) -> Result<AnnotationSetResponse, ClientError> {
+        if let Some(policy) = &request.policy {
+            policy.validate()?;
+        }
+
         self.transport.patch_json(
             format!("annotation-sets/{annotation_set_name}"),
             Some(request),
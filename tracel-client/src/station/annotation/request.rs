@@ -1,9 +1,22 @@
 use serde::{Deserialize, Serialize};
 
+use super::cleanup::{CleanupInterval, CleanupIntervalUnit, CleanupPolicy};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateAnnotationSetRequest {
     pub name: String,
-    pub cleanup_policy: Option<CleanupPolicyRequest>,
+    pub cleanup_policy: Option<CleanupPolicy>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListAnnotationSetsQuery {
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenameAnnotationSetRequest {
+    pub name: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,28 +56,18 @@ pub struct AnnotationSetItemsFilterRequest {
     pub validation: Option<AnnotationSetItemValidationFilterRequest>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum CleanupIntervalUnitRequest {
-    Minutes,
-    Hours,
-}
+#[deprecated(note = "use `CleanupIntervalUnit` instead")]
+pub type CleanupIntervalUnitRequest = CleanupIntervalUnit;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub struct CleanupIntervalRequest {
-    pub unit: CleanupIntervalUnitRequest,
-    pub amount: u32,
-}
+#[deprecated(note = "use `CleanupInterval` instead")]
+pub type CleanupIntervalRequest = CleanupInterval;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CleanupPolicyRequest {
-    pub target_items: u32,
-    pub time_delta: CleanupIntervalRequest,
-}
+#[deprecated(note = "use `CleanupPolicy` instead")]
+pub type CleanupPolicyRequest = CleanupPolicy;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateAnnotationSetCleanupPolicyRequest {
-    pub policy: Option<CleanupPolicyRequest>,
+    pub policy: Option<CleanupPolicy>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
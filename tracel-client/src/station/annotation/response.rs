@@ -1,29 +1,36 @@
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Copy, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum CleanupIntervalUnitResponse {
-    Minutes,
-    Hours,
-}
+use super::cleanup::{CleanupInterval, CleanupIntervalUnit, CleanupPolicy};
 
-#[derive(Debug, Clone, Copy, Deserialize)]
-pub struct CleanupIntervalResponse {
-    pub unit: CleanupIntervalUnitResponse,
-    pub amount: u32,
-}
+#[deprecated(note = "use `CleanupIntervalUnit` instead")]
+pub type CleanupIntervalUnitResponse = CleanupIntervalUnit;
+
+#[deprecated(note = "use `CleanupInterval` instead")]
+pub type CleanupIntervalResponse = CleanupInterval;
+
+#[deprecated(note = "use `CleanupPolicy` instead")]
+pub type CleanupPolicyResponse = CleanupPolicy;
 
 #[derive(Debug, Clone, Deserialize)]
-pub struct CleanupPolicyResponse {
-    pub target_items: u32,
-    pub time_delta: CleanupIntervalResponse,
+pub struct AnnotationSetResponse {
+    pub id: String,
+    pub name: String,
+    pub cleanup_policy: Option<CleanupPolicy>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
-pub struct AnnotationSetResponse {
+pub struct AnnotationSetSummaryResponse {
     pub id: String,
     pub name: String,
-    pub cleanup_policy: Option<CleanupPolicyResponse>,
+    pub cleanup_policy: Option<CleanupPolicy>,
+    pub item_count: u64,
+    pub validated_item_count: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnnotationSetListResponse {
+    pub items: Vec<AnnotationSetSummaryResponse>,
+    pub total_count: u64,
 }
 
 #[serde_with::serde_as]
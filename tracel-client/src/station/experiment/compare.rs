@@ -0,0 +1,230 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{ExperimentClient, MetricSummaryQuery};
+use crate::ClientError;
+
+/// Where a differing value was found on the experiments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ComparedSource {
+    Configuration,
+    Attribute,
+}
+
+/// Best value of a metric group for one experiment.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BestMetricValue {
+    pub value: f64,
+    pub epoch: usize,
+}
+
+/// Best values of one metric group, one entry per compared experiment.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricComparison {
+    pub metric: String,
+    pub group: String,
+    pub best: Vec<Option<BestMetricValue>>,
+}
+
+/// A configuration or attribute value that is not the same on every compared
+/// experiment, one entry per experiment. Nested objects are flattened into
+/// dot-separated keys, prefixed by the configuration name.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValueDifference {
+    pub source: ComparedSource,
+    pub key: String,
+    pub values: Vec<Option<Value>>,
+}
+
+/// Side-by-side comparison of several experiments. Every per-experiment vector
+/// follows the order of `experiments`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExperimentComparison {
+    pub experiments: Vec<i32>,
+    pub metrics: Vec<MetricComparison>,
+    pub differences: Vec<ValueDifference>,
+}
+
+impl ExperimentComparison {
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Renders the comparison as two Markdown tables, best metric values then
+    /// configuration and attribute differences.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let header = self
+            .experiments
+            .iter()
+            .map(|num| format!(" #{num} |"))
+            .collect::<String>();
+        let separator = " --- |".repeat(self.experiments.len());
+
+        out.push_str("### Metrics\n\n");
+        let _ = writeln!(out, "| Metric | Group |{header}");
+        let _ = writeln!(out, "| --- | --- |{separator}");
+        for metric in &self.metrics {
+            let _ = write!(
+                out,
+                "| {} | {} |",
+                markdown_cell(&metric.metric),
+                markdown_cell(&metric.group)
+            );
+            for best in &metric.best {
+                match best {
+                    Some(best) => {
+                        let _ = write!(out, " {} (epoch {}) |", best.value, best.epoch);
+                    }
+                    None => out.push_str(" - |"),
+                }
+            }
+            out.push('\n');
+        }
+
+        out.push_str("\n### Differences\n\n");
+        if self.differences.is_empty() {
+            out.push_str("No configuration or attribute differences.\n");
+            return out;
+        }
+        let _ = writeln!(out, "| Source | Key |{header}");
+        let _ = writeln!(out, "| --- | --- |{separator}");
+        for difference in &self.differences {
+            let _ = write!(
+                out,
+                "| {} | `{}` |",
+                difference.source,
+                markdown_cell(&difference.key)
+            );
+            for value in &difference.values {
+                match value {
+                    Some(value) => {
+                        let _ = write!(out, " `{}` |", markdown_cell(&value.to_string()));
+                    }
+                    None => out.push_str(" - |"),
+                }
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Flattens nested JSON objects into dot-separated keys.
+fn flatten_into(prefix: String, value: &Value, out: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                flatten_into(format!("{prefix}.{key}"), value, out);
+            }
+        }
+        _ => {
+            out.insert(prefix, value.clone());
+        }
+    }
+}
+
+fn flatten(values: &HashMap<String, Value>) -> BTreeMap<String, Value> {
+    let mut out = BTreeMap::new();
+    for (key, value) in values {
+        flatten_into(key.clone(), value, &mut out);
+    }
+    out
+}
+
+fn differences(
+    source: ComparedSource,
+    flattened: &[BTreeMap<String, Value>],
+) -> Vec<ValueDifference> {
+    let keys = flattened
+        .iter()
+        .flat_map(|values| values.keys())
+        .collect::<BTreeSet<_>>();
+
+    keys.into_iter()
+        .filter_map(|key| {
+            let values = flattened
+                .iter()
+                .map(|values| values.get(key).cloned())
+                .collect::<Vec<_>>();
+            let all_equal = values.windows(2).all(|pair| pair[0] == pair[1]);
+
+            (!all_equal).then(|| ValueDifference {
+                source,
+                key: key.clone(),
+                values,
+            })
+        })
+        .collect()
+}
+
+impl ExperimentClient<'_> {
+    /// Compares experiments on the best values of the given metrics and on their
+    /// configurations and attributes.
+    pub fn compare(
+        &self,
+        experiment_nums: &[i32],
+        metrics: &[&str],
+    ) -> Result<ExperimentComparison, ClientError> {
+        let experiments = experiment_nums
+            .iter()
+            .map(|&num| self.get(num))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut metric_rows = Vec::new();
+        for &metric in metrics {
+            let mut by_group: BTreeMap<String, Vec<Option<BestMetricValue>>> = BTreeMap::new();
+            for (index, &experiment_num) in experiment_nums.iter().enumerate() {
+                let summary = self.metric_summary(
+                    experiment_num,
+                    MetricSummaryQuery {
+                        metric: metric.to_string(),
+                    },
+                )?;
+                for group in summary.into_iter().flat_map(|summary| summary.groups) {
+                    by_group
+                        .entry(group.group)
+                        .or_insert_with(|| vec![None; experiment_nums.len()])[index] =
+                        Some(BestMetricValue {
+                            value: group.optimal_value,
+                            epoch: group.epoch,
+                        });
+                }
+            }
+
+            metric_rows.extend(by_group.into_iter().map(|(group, best)| MetricComparison {
+                metric: metric.to_string(),
+                group,
+                best,
+            }));
+        }
+
+        let configurations = experiments
+            .iter()
+            .map(|experiment| flatten(&experiment.configurations))
+            .collect::<Vec<_>>();
+        let attributes = experiments
+            .iter()
+            .map(|experiment| flatten(&experiment.attributes))
+            .collect::<Vec<_>>();
+
+        let mut value_differences = differences(ComparedSource::Configuration, &configurations);
+        value_differences.extend(differences(ComparedSource::Attribute, &attributes));
+
+        Ok(ExperimentComparison {
+            experiments: experiment_nums.to_vec(),
+            metrics: metric_rows,
+            differences: value_differences,
+        })
+    }
+}
@@ -0,0 +1,184 @@
+use std::io::Write;
+
+use thiserror::Error;
+
+use super::{ExperimentClient, MetricAggregatedQuery};
+use crate::ClientError;
+
+/// Requests every logged point: no point cap and no downsampling.
+const FULL_RESOLUTION_MAX_POINTS: i64 = i64::MAX;
+const FULL_RESOLUTION_DOWNSAMPLING_FACTOR: i64 = 1;
+
+const COLUMNS: [&str; 8] = [
+    "experiment",
+    "metric",
+    "group",
+    "epoch",
+    "iteration",
+    "value",
+    "low",
+    "high",
+];
+
+/// One logged point of one metric, in long format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricRow {
+    pub experiment_num: i32,
+    pub metric: String,
+    pub group: String,
+    pub epoch: usize,
+    pub iteration: usize,
+    pub value: f64,
+    pub low: f64,
+    pub high: f64,
+}
+
+/// Every metric point of one or more experiments, one row per point.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricTable {
+    pub rows: Vec<MetricRow>,
+}
+
+impl MetricTable {
+    /// Writes the table as CSV with a header row.
+    pub fn write_csv(&self, mut writer: impl Write) -> Result<(), MetricExportError> {
+        writeln!(writer, "{}", COLUMNS.join(","))?;
+        for row in &self.rows {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{}",
+                row.experiment_num,
+                csv_field(&row.metric),
+                csv_field(&row.group),
+                row.epoch,
+                row.iteration,
+                row.value,
+                row.low,
+                row.high,
+            )?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the table as a single Parquet row group.
+    #[cfg(feature = "parquet")]
+    pub fn write_parquet(&self, writer: impl Write + Send) -> Result<(), MetricExportError> {
+        use std::sync::Arc;
+
+        use arrow_array::{
+            ArrayRef, Float64Array, Int32Array, RecordBatch, StringArray, UInt64Array,
+        };
+        use arrow_schema::{DataType, Field, Schema};
+        use parquet::arrow::ArrowWriter;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(COLUMNS[0], DataType::Int32, false),
+            Field::new(COLUMNS[1], DataType::Utf8, false),
+            Field::new(COLUMNS[2], DataType::Utf8, false),
+            Field::new(COLUMNS[3], DataType::UInt64, false),
+            Field::new(COLUMNS[4], DataType::UInt64, false),
+            Field::new(COLUMNS[5], DataType::Float64, false),
+            Field::new(COLUMNS[6], DataType::Float64, false),
+            Field::new(COLUMNS[7], DataType::Float64, false),
+        ]));
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from_iter_values(
+                self.rows.iter().map(|row| row.experiment_num),
+            )),
+            Arc::new(StringArray::from_iter_values(
+                self.rows.iter().map(|row| row.metric.as_str()),
+            )),
+            Arc::new(StringArray::from_iter_values(
+                self.rows.iter().map(|row| row.group.as_str()),
+            )),
+            Arc::new(UInt64Array::from_iter_values(
+                self.rows.iter().map(|row| row.epoch as u64),
+            )),
+            Arc::new(UInt64Array::from_iter_values(
+                self.rows.iter().map(|row| row.iteration as u64),
+            )),
+            Arc::new(Float64Array::from_iter_values(
+                self.rows.iter().map(|row| row.value),
+            )),
+            Arc::new(Float64Array::from_iter_values(
+                self.rows.iter().map(|row| row.low),
+            )),
+            Arc::new(Float64Array::from_iter_values(
+                self.rows.iter().map(|row| row.high),
+            )),
+        ];
+
+        let batch = RecordBatch::try_new(schema.clone(), columns)?;
+        let mut writer = ArrowWriter::try_new(writer, schema, None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(())
+    }
+}
+
+/// Quotes a CSV field when it contains a delimiter, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum MetricExportError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "parquet")]
+    #[error(transparent)]
+    Arrow(#[from] arrow_schema::ArrowError),
+    #[cfg(feature = "parquet")]
+    #[error(transparent)]
+    Parquet(#[from] parquet::errors::ParquetError),
+}
+
+impl ExperimentClient<'_> {
+    /// Fetches every metric of the given experiments at full resolution.
+    ///
+    /// Metrics are enumerated through [`metric_metadata`](Self::metric_metadata), so
+    /// this issues one request per experiment plus one per metric.
+    pub fn export_metrics(&self, experiment_nums: &[i32]) -> Result<MetricTable, ClientError> {
+        let mut table = MetricTable::default();
+
+        for &experiment_num in experiment_nums {
+            let metadata = self.metric_metadata(experiment_num)?;
+            for metric in metadata.metric_types {
+                let response = self.metrics(
+                    experiment_num,
+                    MetricAggregatedQuery {
+                        metric: metric.clone(),
+                        max_points: FULL_RESOLUTION_MAX_POINTS,
+                        downsampling_factor: FULL_RESOLUTION_DOWNSAMPLING_FACTOR,
+                    },
+                )?;
+                let Some(response) = response else {
+                    continue;
+                };
+
+                for group in response.groups {
+                    table
+                        .rows
+                        .extend(group.entries.into_iter().map(|entry| MetricRow {
+                            experiment_num,
+                            metric: metric.clone(),
+                            group: group.name.clone(),
+                            epoch: entry.epoch,
+                            iteration: entry.iteration,
+                            value: entry.value,
+                            low: entry.low,
+                            high: entry.high,
+                        }));
+                }
+            }
+        }
+
+        Ok(table)
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::VecDeque;
+use std::thread;
+use std::time::Duration;
+
+use super::{ExperimentClient, LogUrlsQuery};
+use crate::{ClientError, transport::ApiTransport};
+
+const DEFAULT_TAIL_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Iterator over the log lines of an experiment, following new lines as they are
+/// written. Created by [`ExperimentClient::tail`].
+///
+/// The iterator polls the realtime logs, blocking between polls, and ends once the
+/// experiment is no longer running and every line has been yielded. A failed poll
+/// yields the error and ends the iteration.
+///
+/// New lines are found by lining each snapshot up with the previous one: the
+/// longest end of the previous snapshot that the new one starts with is taken as
+/// already yielded. This follows a buffer that grows, drops its oldest lines or is
+/// reset, without yielding a line twice as long as it is still in both snapshots.
+pub struct LogTail<'a> {
+    client: ExperimentClient<'a>,
+    experiment_num: i32,
+    poll_interval: Duration,
+    previous: Vec<String>,
+    pending: VecDeque<String>,
+    polled: bool,
+    finished: bool,
+}
+
+impl<'a> LogTail<'a> {
+    fn new(transport: &'a ApiTransport, experiment_num: i32) -> Self {
+        Self {
+            client: ExperimentClient::new(transport),
+            experiment_num,
+            poll_interval: DEFAULT_TAIL_POLL_INTERVAL,
+            previous: Vec::new(),
+            pending: VecDeque::new(),
+            polled: false,
+            finished: false,
+        }
+    }
+
+    /// Sets the delay between two polls when no new line is available.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    fn poll(&mut self) -> Result<(), ClientError> {
+        if self.polled {
+            thread::sleep(self.poll_interval);
+        }
+        self.polled = true;
+
+        let response = self.client.realtime_logs(self.experiment_num)?;
+        let overlap = overlap(&self.previous, &response.logs);
+        self.pending
+            .extend(response.logs[overlap..].iter().cloned());
+        self.previous = response.logs;
+        self.finished = !response.running;
+
+        Ok(())
+    }
+}
+
+/// Length of the longest end of `previous` that `current` starts with.
+fn overlap(previous: &[String], current: &[String]) -> usize {
+    (1..=previous.len().min(current.len()))
+        .rev()
+        .find(|&len| previous[previous.len() - len..] == current[..len])
+        .unwrap_or(0)
+}
+
+impl Iterator for LogTail<'_> {
+    type Item = Result<String, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(line) = self.pending.pop_front() {
+                return Some(Ok(line));
+            }
+            if self.finished {
+                return None;
+            }
+            if let Err(error) = self.poll() {
+                self.finished = true;
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+impl<'a> ExperimentClient<'a> {
+    /// Follows the logs of an experiment until it stops running.
+    pub fn tail(&self, experiment_num: i32) -> LogTail<'a> {
+        LogTail::new(self.transport, experiment_num)
+    }
+
+    /// Downloads the stored logs of an experiment, fetching every log URL in order.
+    ///
+    /// `query.start` is a byte offset into the logs: pass the number of bytes already
+    /// downloaded to resume an interrupted download.
+    pub fn download_logs(
+        &self,
+        experiment_num: i32,
+        query: LogUrlsQuery,
+    ) -> Result<Vec<u8>, ClientError> {
+        let response = self.logs(experiment_num, query)?;
+
+        let mut logs = Vec::new();
+        for log_url in response.urls {
+            let range = (!log_url.size_header.is_empty()).then_some(log_url.size_header.as_str());
+            logs.extend(
+                self.transport
+                    .download_bytes_from_url(&log_url.url, range)?,
+            );
+        }
+
+        Ok(logs)
+    }
+}
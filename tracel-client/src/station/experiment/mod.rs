@@ -1,19 +1,31 @@
+pub mod compare;
+pub mod export;
+pub mod logs;
 pub mod request;
 pub mod response;
 
+pub use crate::experiment::request::{
+    ExperimentFilterRequest, ExperimentJsonComparisonRequest, ExperimentJsonFilterRequest,
+    ExperimentJsonPathRequest, ExperimentSortField, ExperimentSortRequest, SortDirection,
+};
+pub use compare::{
+    BestMetricValue, ComparedSource, ExperimentComparison, MetricComparison, ValueDifference,
+};
+pub use export::{MetricExportError, MetricRow, MetricTable};
+pub use logs::LogTail;
 pub use request::{
     AddFilesRequest, ArtifactFileSpecRequest, CompleteUploadRequest, CreateArtifactRequest,
     CreateExperimentRequest, ListArtifactsQuery, ListExperimentsQuery, LogUrlsQuery,
     MetricAggregatedQuery, MetricSummaryQuery,
 };
 pub use response::{
-    ArtifactCreationResponse, ArtifactDownloadResponse, ArtifactListResponse, ArtifactResponse,
-    DeleteExperimentArtifactResponse, ExperimentInputResponse, ExperimentListResponse,
-    ExperimentLogResponse, ExperimentResponse, LoadLogUrlResponse, LoadLogUrlsResponse,
-    MetricEntryResponse, MetricGroupResponse, MetricMetadataResponse, MetricResponse,
-    MetricSummaryGroupResponse, MetricSummaryResponse, MultipartUploadResponse,
-    PresignedArtifactFileUploadUrlsResponse, PresignedArtifactFileUrlResponse,
-    PresignedUploadUrlResponse,
+    ArtifactCreationResponse, ArtifactDownloadResponse, ArtifactListResponse,
+    ArtifactManifestResponse, ArtifactResponse, DeleteExperimentArtifactResponse,
+    ExperimentInputResponse, ExperimentListResponse, ExperimentLogResponse, ExperimentResponse,
+    LoadLogUrlResponse, LoadLogUrlsResponse, MetricEntryResponse, MetricGroupResponse,
+    MetricMetadataResponse, MetricResponse, MetricSummaryGroupResponse, MetricSummaryResponse,
+    MultipartUploadResponse, PresignedArtifactFileUploadUrlsResponse,
+    PresignedArtifactFileUrlResponse, PresignedUploadUrlResponse,
 };
 
 use crate::{ClientError, WebSocketClient, transport::ApiTransport, websocket::WebSocketError};
@@ -49,6 +61,12 @@ impl<'a> ExperimentClient<'a> {
             if let Some(per_page) = query.per_page {
                 pairs.append_pair("per_page", &per_page.to_string());
             }
+            if let Some(filter) = &query.filter {
+                pairs.extend_pairs(filter.query_pairs()?);
+            }
+            if let Some(sort) = &query.sort {
+                pairs.extend_pairs(sort.query_pairs());
+            }
         }
 
         self.transport.get_json(url)
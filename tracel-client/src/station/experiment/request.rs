@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::experiment::request::{ExperimentFilterRequest, ExperimentSortRequest};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateExperimentRequest {
     pub name: Option<String>,
@@ -15,6 +17,10 @@ pub struct CreateExperimentRequest {
 pub struct ListExperimentsQuery {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
+    #[serde(default)]
+    pub filter: Option<ExperimentFilterRequest>,
+    #[serde(default)]
+    pub sort: Option<ExperimentSortRequest>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -3,6 +3,11 @@ use std::collections::HashMap;
 use serde::Deserialize;
 use serde_json::Value;
 
+pub use crate::experiment::response::ExperimentInputResponse;
+
+use crate::experiment::config::ExperimentConfigExt;
+use crate::station::model::FileManifestResponse;
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ExperimentResponse {
     pub id: i32,
@@ -17,11 +22,14 @@ pub struct ExperimentResponse {
     pub attributes: HashMap<String, Value>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
-pub enum ExperimentInputResponse {
-    Artifact { artifact_id: String },
-    Model { model_version_id: String },
+impl ExperimentConfigExt for ExperimentResponse {
+    fn configurations(&self) -> &HashMap<String, Value> {
+        &self.configurations
+    }
+
+    fn arguments_value(&self) -> &Value {
+        &self.arguments
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -111,9 +119,11 @@ pub struct ArtifactResponse {
     pub name: String,
     pub kind: String,
     pub experiment_num: i32,
-    pub manifest: Value,
+    pub manifest: ArtifactManifestResponse,
 }
 
+pub type ArtifactManifestResponse = FileManifestResponse;
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ArtifactListResponse {
     pub items: Vec<ArtifactResponse>,
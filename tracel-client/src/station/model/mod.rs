@@ -1,15 +1,30 @@
 pub mod request;
 pub mod response;
 
-pub use request::{CreateModelRequest, UploadModelFileSpecRequest, UploadModelVersionRequest};
+pub use crate::model::lineage::ModelLineage;
+pub use crate::model::response::{
+    BurnRecordMetadataResponse, RecordFormatResponse, RecordPrecisionResponse,
+};
+pub use request::{
+    CreateModelRequest, SetModelAliasRequest, UploadModelFileSpecRequest, UploadModelVersionRequest,
+};
 pub use response::{
-    ExperimentSourceResponse, FileDescriptorResponse, ModelDownloadResponse, ModelListResponse,
-    ModelResponse, ModelVersionListResponse, ModelVersionManifestResponse, ModelVersionResponse,
+    ExperimentSourceResponse, FileDescriptorResponse, FileManifestResponse, ModelAliasListResponse,
+    ModelAliasResponse, ModelDownloadResponse, ModelListResponse, ModelResponse,
+    ModelVersionListResponse, ModelVersionManifestResponse, ModelVersionResponse,
     PresignedModelFileUploadUrlsResponse, PresignedModelFileUrlResponse,
     PresignedUploadUrlResponse, UploadModelResponse,
 };
 
-use crate::{ClientError, transport::ApiTransport};
+use crate::{
+    ClientError,
+    model::{
+        lineage::LineageVersion,
+        reference::{ModelAlias, ModelVersionSelector},
+    },
+    station::experiment::ExperimentClient,
+    transport::ApiTransport,
+};
 
 pub struct ModelClient<'a> {
     transport: &'a ApiTransport,
@@ -57,21 +72,85 @@ impl<'a> ModelClient<'a> {
         )
     }
 
+    /// Get a model version, selected by number or through an alias. For a
+    /// [`ModelRef`](crate::model::reference::ModelRef), pass its `model` and `selector`.
     pub fn version(
         &self,
         model_name: &str,
-        version: u32,
+        version: impl Into<ModelVersionSelector>,
     ) -> Result<ModelVersionResponse, ClientError> {
         self.transport
-            .get_json(format!("models/{model_name}/versions/{version}"))
+            .get_json(format!("models/{model_name}/{}", version.into().path()))
     }
 
     pub fn download(
         &self,
         model_name: &str,
-        version: u32,
+        version: impl Into<ModelVersionSelector>,
     ) -> Result<ModelDownloadResponse, ClientError> {
+        self.transport.get_json(format!(
+            "models/{model_name}/{}/download",
+            version.into().path()
+        ))
+    }
+
+    pub fn aliases(&self, model_name: &str) -> Result<ModelAliasListResponse, ClientError> {
+        self.transport
+            .get_json(format!("models/{model_name}/aliases"))
+    }
+
+    /// Point an alias at a version, moving it if it already exists.
+    pub fn set_alias(
+        &self,
+        model_name: &str,
+        alias: &ModelAlias,
+        version: u32,
+    ) -> Result<ModelAliasResponse, ClientError> {
+        self.transport.put_json(
+            format!("models/{model_name}/aliases/{alias}"),
+            Some(SetModelAliasRequest { version }),
+        )
+    }
+
+    pub fn delete_alias(&self, model_name: &str, alias: &ModelAlias) -> Result<(), ClientError> {
+        self.transport
+            .delete(format!("models/{model_name}/aliases/{alias}"))
+    }
+
+    /// Get a model version by its id, as recorded in experiment inputs.
+    pub fn version_by_id(
+        &self,
+        model_version_id: &str,
+    ) -> Result<ModelVersionResponse, ClientError> {
         self.transport
-            .get_json(format!("models/{model_name}/versions/{version}/download"))
+            .get_json(format!("model-versions/{model_version_id}"))
+    }
+
+    /// Walks back from a model version through the experiment that produced it to
+    /// the inputs it used, following inputs that are model versions recursively.
+    pub fn lineage(
+        &self,
+        model_name: &str,
+        version: impl Into<ModelVersionSelector>,
+    ) -> Result<ModelLineage, ClientError> {
+        let experiments = ExperimentClient::new(self.transport);
+
+        ModelLineage::walk(
+            self.version(model_name, version)?.into(),
+            |id| self.version_by_id(id).map(Into::into),
+            |experiment_num| Ok(experiments.get(experiment_num)?.inputs),
+        )
+    }
+}
+
+impl From<ModelVersionResponse> for LineageVersion {
+    fn from(model_version: ModelVersionResponse) -> Self {
+        LineageVersion {
+            id: model_version.id,
+            version: model_version.version,
+            experiment_num: model_version
+                .experiment
+                .map(|experiment| experiment.experiment_num),
+        }
     }
 }
@@ -17,3 +17,8 @@ pub struct UploadModelFileSpecRequest {
     pub size_bytes: u64,
     pub checksum: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetModelAliasRequest {
+    pub version: u32,
+}
@@ -1,5 +1,8 @@
 use serde::Deserialize;
 
+use crate::model::reference::ModelAlias;
+use crate::model::response::BurnRecordMetadataResponse;
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ModelResponse {
     pub id: String,
@@ -27,15 +30,31 @@ pub struct ModelVersionResponse {
 }
 
 #[derive(Debug, Clone, Deserialize)]
-pub struct ModelVersionManifestResponse {
+pub struct FileManifestResponse {
     pub files: Vec<FileDescriptorResponse>,
 }
 
+impl FileManifestResponse {
+    /// Total size in bytes of the files listed in the manifest.
+    pub fn total_size_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.size_bytes).sum()
+    }
+
+    pub fn file(&self, rel_path: &str) -> Option<&FileDescriptorResponse> {
+        self.files.iter().find(|file| file.rel_path == rel_path)
+    }
+}
+
+pub type ModelVersionManifestResponse = FileManifestResponse;
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct FileDescriptorResponse {
     pub rel_path: String,
     pub size_bytes: u64,
     pub checksum: String,
+    /// Present when the file is a Burn record.
+    #[serde(default)]
+    pub record: Option<BurnRecordMetadataResponse>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -81,3 +100,15 @@ pub struct PresignedUploadUrlResponse {
     pub url: String,
     pub size_bytes: u64,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModelAliasResponse {
+    pub alias: ModelAlias,
+    pub version: u32,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModelAliasListResponse {
+    pub items: Vec<ModelAliasResponse>,
+}
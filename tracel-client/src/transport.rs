@@ -116,6 +116,16 @@ impl ApiTransport {
         Ok(serde_json::from_slice::<R>(&bytes)?)
     }
 
+    pub fn put_json<T, R>(&self, path: impl AsRef<str>, body: Option<T>) -> Result<R, ClientError>
+    where
+        T: serde::Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        let response = self.req(reqwest::Method::PUT, path, body)?;
+        let bytes = response.bytes()?;
+        Ok(serde_json::from_slice::<R>(&bytes)?)
+    }
+
     pub fn delete(&self, path: impl AsRef<str>) -> Result<(), ClientError> {
         self.req(reqwest::Method::DELETE, path, None::<serde_json::Value>)
             .map(|_| ())
@@ -167,6 +177,25 @@ impl ApiTransport {
         Ok(())
     }
 
+    /// Download raw bytes from an absolute (presigned) URL via GET, optionally
+    /// restricted to a byte range (`Range` header value, e.g. `bytes=1024-`).
+    ///
+    /// Like [`upload_bytes_to_url`](Self::upload_bytes_to_url), this does NOT join
+    /// the path with `base_url` and does NOT attach auth.
+    pub fn download_bytes_from_url(
+        &self,
+        url: &str,
+        range: Option<&str>,
+    ) -> Result<Vec<u8>, ClientError> {
+        let mut request = self.http_client.get(url);
+        if let Some(range) = range {
+            request = request.header(reqwest::header::RANGE, range);
+        }
+
+        let bytes = request.send()?.map_to_tracel_err()?.bytes()?;
+        Ok(bytes.to_vec())
+    }
+
     pub fn join(&self, path: &str) -> Url {
         self.join_versioned(path, 1)
     }
@@ -64,8 +64,12 @@ impl WebSocketClient {
                 req.headers_mut().insert(COOKIE, cookie.parse().unwrap());
             }
             Auth::Bearer(token) => {
-                req.headers_mut()
-                    .insert("Authorization", format!("Bearer {token}").parse().unwrap());
+                req.headers_mut().insert(
+                    "Authorization",
+                    format!("Bearer {token}")
+                        .parse()
+                        .expect("Bearer token should be checked by Client::with_bearer_token"),
+                );
             }
         }
 